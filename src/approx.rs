@@ -0,0 +1,144 @@
+//! Approximate wording such as "about 2 hours" or "less than a minute".
+
+use crate::Unit;
+
+/// Units used for approximate wording, longest first. Weeks are skipped, so
+/// three weeks is "about 21 days", as in date-fns. Other units are only used
+/// when they are the `smallest_unit`.
+const APPROX_UNITS: [Unit; 7] = [
+    Unit::Years,
+    Unit::Months,
+    Unit::Days,
+    Unit::Hours,
+    Unit::Minutes,
+    Unit::Seconds,
+    Unit::Milliseconds,
+];
+
+/// Options for `format_approx()`.
+///
+/// New options may be added in minor releases, so build them with
+/// [`ApproxOptions::new()`] and the setters rather than a struct expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct ApproxOptions {
+    /// Fraction of a unit from which "over N" is used instead of "about N".
+    /// Defaults to `0.25`.
+    pub over: f64,
+    /// Fraction of a unit from which "almost N+1" is used instead of
+    /// "over N". Defaults to `0.75`.
+    pub almost: f64,
+    /// Values shorter than one of this unit are "less than a ...". Defaults
+    /// to [`Unit::Minutes`].
+    pub smallest_unit: Unit,
+}
+
+impl ApproxOptions {
+    /// Create the default options.
+    pub const fn new() -> ApproxOptions {
+        ApproxOptions {
+            over: 0.25,
+            almost: 0.75,
+            smallest_unit: Unit::Minutes,
+        }
+    }
+
+    /// Set [`over`](ApproxOptions::over).
+    pub const fn over(mut self, over: f64) -> ApproxOptions {
+        self.over = over;
+        self
+    }
+
+    /// Set [`almost`](ApproxOptions::almost).
+    pub const fn almost(mut self, almost: f64) -> ApproxOptions {
+        self.almost = almost;
+        self
+    }
+
+    /// Set [`smallest_unit`](ApproxOptions::smallest_unit).
+    pub const fn smallest_unit(mut self, smallest_unit: Unit) -> ApproxOptions {
+        self.smallest_unit = smallest_unit;
+        self
+    }
+}
+
+impl Default for ApproxOptions {
+    fn default() -> ApproxOptions {
+        ApproxOptions::new()
+    }
+}
+
+/// Format the given milliseconds as an approximate phrase.
+///
+/// The largest unit from minutes (or `smallest_unit`) up to years is chosen,
+/// and the fraction of a unit left over picks the wording: "about 2 hours"
+/// below `over`, "over 2 hours" below `almost`, and "almost 3 hours" from
+/// there. The sign is ignored, as the phrase describes a distance.
+///
+/// # Arguments
+///
+/// * `ms` - milliseconds to format
+/// * `options` - Options for the wording (`over` and `almost` thresholds,
+///   `smallest_unit`)
+///
+/// # Returns
+///
+/// The formatted phrase
+///
+/// # Examples
+///
+/// ```
+/// use millis::{format_approx, ApproxOptions, Unit};
+///
+/// assert_eq!(format_approx(30000, None), "less than a minute");
+/// assert_eq!(format_approx(3900000, None), "about 1 hour");
+/// assert_eq!(format_approx(-4500000, None), "over 1 hour");
+/// assert_eq!(format_approx(59000000000, None), "almost 2 years");
+///
+/// let seconds = ApproxOptions::new().smallest_unit(Unit::Seconds);
+/// assert_eq!(format_approx(30000, Some(seconds)), "about 30 seconds");
+/// ```
+pub fn format_approx(ms: i64, options: Option<ApproxOptions>) -> String {
+    let opts = options.unwrap_or_default();
+    let ms_abs = ms.unsigned_abs() as f64;
+
+    let mut units: Vec<Unit> = APPROX_UNITS
+        .iter()
+        .copied()
+        .filter(|&u| u > opts.smallest_unit)
+        .collect();
+    units.push(opts.smallest_unit);
+    let Some(index) = units.iter().position(|u| ms_abs >= u.millis()) else {
+        let article = if opts.smallest_unit == Unit::Hours {
+            "an"
+        } else {
+            "a"
+        };
+        return format!("less than {} {}", article, opts.smallest_unit.names().0);
+    };
+
+    let unit = units[index];
+    let value = ms_abs / unit.millis();
+    let whole = value.trunc();
+    let fraction = value - whole;
+    if fraction < opts.over {
+        return format!("about {}", count(whole, unit));
+    }
+    if fraction < opts.almost {
+        return format!("over {}", count(whole, unit));
+    }
+
+    // "almost 60 minutes" reads better as "almost 1 hour"
+    match index.checked_sub(1).map(|i| units[i]) {
+        Some(larger) if (whole + 1.0) * unit.millis() >= larger.millis() => {
+            format!("almost {}", count(1.0, larger))
+        }
+        _ => format!("almost {}", count(whole + 1.0, unit)),
+    }
+}
+
+/// A whole number of units, e.g. "1 hour" or "3 days"
+fn count(n: f64, unit: Unit) -> String {
+    let (singular, plural) = unit.names();
+    format!("{} {}", n, if n == 1.0 { singular } else { plural })
+}
@@ -0,0 +1,148 @@
+//! Splitting milliseconds into components.
+
+use crate::Unit;
+
+/// Milliseconds split into years, months, weeks, days, hours, minutes,
+/// seconds and milliseconds.
+///
+/// Components are non-negative; the sign of the duration is kept separately in
+/// `negative`. Months and years use the same fixed lengths as `parse()` and
+/// `format()`.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{Breakdown, Unit};
+///
+/// let b = Breakdown::new(93784005);
+/// assert_eq!((b.days, b.hours, b.minutes, b.seconds, b.milliseconds), (1, 2, 3, 4, 5));
+///
+/// // Never go above hours, and drop the milliseconds
+/// let b = Breakdown::with_units(93784005, Unit::Hours, Unit::Seconds);
+/// assert_eq!((b.hours, b.minutes, b.seconds, b.milliseconds), (26, 3, 4, 0));
+///
+/// assert_eq!(Breakdown::new(-93784005).total_millis().unwrap(), -93784005);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Breakdown {
+    /// `true` if the duration is negative.
+    pub negative: bool,
+    /// Whole years (365.25 days each).
+    pub years: u64,
+    /// Whole months (one twelfth of a year each).
+    pub months: u64,
+    /// Whole weeks.
+    pub weeks: u64,
+    /// Whole days.
+    pub days: u64,
+    /// Whole hours.
+    pub hours: u64,
+    /// Whole minutes.
+    pub minutes: u64,
+    /// Whole seconds.
+    pub seconds: u64,
+    /// Remaining milliseconds.
+    pub milliseconds: u64,
+}
+
+impl Breakdown {
+    /// Split `ms` into every component from years down to milliseconds.
+    pub fn new(ms: i64) -> Breakdown {
+        Breakdown::with_units(ms, Unit::Years, Unit::Milliseconds)
+    }
+
+    /// Split `ms` into the components from `largest` down to `smallest`.
+    ///
+    /// Time above `largest` is carried into the `largest` component, and time
+    /// below `smallest` is dropped (truncated towards zero). Units without a
+    /// component are clamped: anything longer than years counts as years,
+    /// microseconds as milliseconds, and fortnights as weeks. The bounds may be
+    /// given in either order.
+    pub fn with_units(ms: i64, largest: Unit, smallest: Unit) -> Breakdown {
+        let (largest, smallest) = (clamp(largest.max(smallest)), clamp(largest.min(smallest)));
+
+        let mut breakdown = Breakdown {
+            negative: ms < 0,
+            ..Default::default()
+        };
+        let mut rest = ms.unsigned_abs();
+        for unit in COMPONENTS {
+            if unit > largest || unit < smallest {
+                continue;
+            }
+            let size = unit.millis() as u64;
+            *breakdown.component_mut(unit) = rest / size;
+            rest %= size;
+        }
+        breakdown
+    }
+
+    /// Sum the components back into milliseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the total does not fit in an `i64`.
+    pub fn total_millis(&self) -> Result<i64, String> {
+        // Cannot overflow: each part is below 2^64 * 2^35
+        let magnitude: i128 = COMPONENTS
+            .iter()
+            .map(|&unit| i128::from(self.component(unit)) * unit.millis() as i128)
+            .sum();
+        let total = if self.negative { -magnitude } else { magnitude };
+        i64::try_from(total).map_err(|_| format!("Duration out of range. value={:?}", self))
+    }
+
+    pub(crate) fn component(&self, unit: Unit) -> u64 {
+        match unit {
+            Unit::Years => self.years,
+            Unit::Months => self.months,
+            Unit::Weeks => self.weeks,
+            Unit::Days => self.days,
+            Unit::Hours => self.hours,
+            Unit::Minutes => self.minutes,
+            Unit::Seconds => self.seconds,
+            _ => self.milliseconds,
+        }
+    }
+
+    fn component_mut(&mut self, unit: Unit) -> &mut u64 {
+        match unit {
+            Unit::Years => &mut self.years,
+            Unit::Months => &mut self.months,
+            Unit::Weeks => &mut self.weeks,
+            Unit::Days => &mut self.days,
+            Unit::Hours => &mut self.hours,
+            Unit::Minutes => &mut self.minutes,
+            Unit::Seconds => &mut self.seconds,
+            _ => &mut self.milliseconds,
+        }
+    }
+}
+
+impl From<i64> for Breakdown {
+    fn from(ms: i64) -> Breakdown {
+        Breakdown::new(ms)
+    }
+}
+
+// Units with a component, longest first
+const COMPONENTS: [Unit; 8] = [
+    Unit::Years,
+    Unit::Months,
+    Unit::Weeks,
+    Unit::Days,
+    Unit::Hours,
+    Unit::Minutes,
+    Unit::Seconds,
+    Unit::Milliseconds,
+];
+
+/// Map a unit onto the nearest unit with a component
+fn clamp(unit: Unit) -> Unit {
+    match unit {
+        Unit::Microseconds => Unit::Milliseconds,
+        Unit::Fortnights => Unit::Weeks,
+        u if u > Unit::Years => Unit::Years,
+        u => u,
+    }
+}
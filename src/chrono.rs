@@ -0,0 +1,168 @@
+//! `chrono` support, behind the `chrono` feature.
+
+use crate::decimal::ExactSum;
+use crate::unicode::split_number;
+use crate::{
+    Options, ParseOptions, RoundingMode, ToMillis, Unit, format_i128, parse_nanos, parse_parts,
+};
+use ::chrono::{DateTime, Months, TimeDelta, TimeZone};
+
+/// Nanoseconds in a millisecond
+const NANOS_PER_MS: u128 = 1_000_000;
+
+/// Parse the given string into a `TimeDelta`.
+///
+/// The grammar is the same as `parse()`, but the value is kept to the
+/// nanosecond instead of being rounded to whole milliseconds, with ties
+/// rounded away from zero.
+///
+/// # Errors
+///
+/// Returns an error for invalid input or a value outside the range of
+/// `TimeDelta`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeDelta;
+/// use millis::parse_time_delta;
+///
+/// assert_eq!(parse_time_delta("1h 30m").unwrap(), TimeDelta::minutes(90));
+/// assert_eq!(parse_time_delta("-2.5s").unwrap(), TimeDelta::milliseconds(-2500));
+/// assert_eq!(parse_time_delta("1.5 µs").unwrap(), TimeDelta::nanoseconds(1500));
+/// assert!(parse_time_delta("invalid").is_err());
+/// ```
+pub fn parse_time_delta(s: &str) -> Result<TimeDelta, String> {
+    time_delta(parse_nanos(s)?).ok_or_else(|| format!("Duration out of range. value={:?}", s))
+}
+
+/// Build a `TimeDelta` from nanoseconds, if it is in range
+fn time_delta(nanos: i128) -> Option<TimeDelta> {
+    let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+    TimeDelta::new(secs, nanos.rem_euclid(1_000_000_000) as u32)
+}
+
+/// Format a `TimeDelta` with the given options, like `format()`.
+///
+/// The delta is rounded to the nearest millisecond first, with ties rounded
+/// away from zero.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeDelta;
+/// use millis::{format_time_delta, Options};
+///
+/// assert_eq!(format_time_delta(TimeDelta::minutes(90), None), "2h");
+/// assert_eq!(
+///     format_time_delta(TimeDelta::seconds(-90), Some(Options::new().long(true))),
+///     "-2 minutes"
+/// );
+/// assert_eq!(format_time_delta(TimeDelta::microseconds(1500), None), "2ms");
+/// ```
+pub fn format_time_delta(delta: TimeDelta, options: Option<Options>) -> String {
+    format_i128(delta_millis(delta), options)
+}
+
+/// The delta in milliseconds, rounded half away from zero
+fn delta_millis(delta: TimeDelta) -> i128 {
+    let nanos = i128::from(delta.num_seconds()) * 1_000_000_000 + i128::from(delta.subsec_nanos());
+    let half = if nanos < 0 { -500_000 } else { 500_000 };
+    (nanos + half) / 1_000_000
+}
+
+/// Implementation for TimeDelta - converts the delta to a formatted string
+impl ToMillis for TimeDelta {
+    type Output = Result<String, String>;
+
+    fn to_millis(self) -> Result<String, String> {
+        Ok(format_time_delta(self, None))
+    }
+}
+
+/// Add the duration in the given string to a date and time, treating months
+/// and longer units as calendar months.
+///
+/// Months, years, decades, centuries and millennia move the date by whole
+/// calendar months, clamping the day to the end of a shorter month, so
+/// "1 month" after January 31 is the last day of February. They must be whole
+/// numbers. The other units are then added as an exact `TimeDelta`. A leading
+/// `-` subtracts the whole duration.
+///
+/// # Errors
+///
+/// Returns an error for invalid input, a fractional calendar unit, or a
+/// result outside the range of `DateTime`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use millis::add_calendar;
+///
+/// let start = Utc.with_ymd_and_hms(2024, 1, 31, 12, 0, 0).unwrap();
+/// assert_eq!(
+///     add_calendar(start, "1mo").unwrap(),
+///     Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap()
+/// );
+/// assert_eq!(
+///     add_calendar(start, "1y 2d 6h").unwrap(),
+///     Utc.with_ymd_and_hms(2025, 2, 2, 18, 0, 0).unwrap()
+/// );
+/// assert_eq!(
+///     add_calendar(start, "-1 month").unwrap(),
+///     Utc.with_ymd_and_hms(2023, 12, 31, 12, 0, 0).unwrap()
+/// );
+/// assert!(add_calendar(start, "1.5 months").is_err());
+/// ```
+pub fn add_calendar<Tz: TimeZone>(
+    date_time: DateTime<Tz>,
+    s: &str,
+) -> Result<DateTime<Tz>, String> {
+    let opts = ParseOptions::default();
+    let parsed = parse_parts(s, &opts)?;
+    let out_of_range = || format!("Date out of range. value={:?}", s);
+
+    let mut months: u32 = 0;
+    let mut rest = ExactSum::default();
+    for &(value, unit) in &parsed.parts {
+        let Some(per_unit) = calendar_months(unit) else {
+            rest.add(value, unit).ok_or_else(out_of_range)?;
+            continue;
+        };
+        let whole = split_number(value)
+            .filter(|n| n.decimals == 0 && !n.sticky && n.fraction.is_none())
+            .and_then(|n| n.whole)
+            .ok_or_else(|| format!("Calendar units must be whole numbers. value={:?}", s))?;
+        months = u32::try_from(whole)
+            .ok()
+            .and_then(|whole| whole.checked_mul(per_unit))
+            .and_then(|n| months.checked_add(n))
+            .ok_or_else(out_of_range)?;
+    }
+    let delta = rest
+        .round(NANOS_PER_MS, parsed.negative, RoundingMode::HalfUp)
+        .and_then(time_delta)
+        .ok_or_else(out_of_range)?;
+
+    let date_time = if parsed.negative {
+        date_time.checked_sub_months(Months::new(months))
+    } else {
+        date_time.checked_add_months(Months::new(months))
+    };
+    date_time
+        .and_then(|date_time| date_time.checked_add_signed(delta))
+        .ok_or_else(out_of_range)
+}
+
+/// Calendar months in one of `unit`, for units that are whole months
+fn calendar_months(unit: Unit) -> Option<u32> {
+    match unit {
+        Unit::Months => Some(1),
+        Unit::Years => Some(12),
+        Unit::Decades => Some(120),
+        Unit::Centuries => Some(1200),
+        Unit::Millennia => Some(12000),
+        _ => None,
+    }
+}
@@ -0,0 +1,240 @@
+//! `clap` support, behind the `clap` feature.
+
+use crate::{Millis, format_exact, parse};
+use ::clap::builder::{StyledStr, TypedValueParser, ValueParserFactory};
+use ::clap::error::ErrorKind;
+use ::clap::{Arg, Command, Error};
+use std::ffi::OsStr;
+use std::marker::PhantomData;
+use std::time::Duration;
+use value::Value;
+
+/// Example values for help text and error tips, when they are within the
+/// bounds
+const HINTS: [&str; 5] = ["500ms", "30s", "5m", "1h", "1d"];
+
+mod value {
+    /// A type `DurationParser` can produce
+    pub trait Value: Clone + Send + Sync + 'static {
+        /// Convert parsed milliseconds, or describe why they do not fit
+        fn from_millis(ms: i64) -> Result<Self, String>;
+    }
+}
+
+impl Value for i64 {
+    fn from_millis(ms: i64) -> Result<i64, String> {
+        Ok(ms)
+    }
+}
+
+impl Value for Millis {
+    fn from_millis(ms: i64) -> Result<Millis, String> {
+        Ok(Millis(ms))
+    }
+}
+
+impl Value for Duration {
+    fn from_millis(ms: i64) -> Result<Duration, String> {
+        u64::try_from(ms)
+            .map(Duration::from_millis)
+            .map_err(|_| "duration must not be negative".to_string())
+    }
+}
+
+/// A clap value parser for duration arguments such as `--timeout 30s`.
+///
+/// The argument is read with `parse()` and produced as milliseconds (`i64`),
+/// a [`Millis`] or a `std::time::Duration`, which rejects negative values.
+/// `min()` and `max()` bound the accepted milliseconds. Errors name the
+/// argument, explain what is wrong and suggest values within the bounds.
+/// `configure()` sets the parser on an `Arg` and adds those values to its help
+/// text, and `examples()` gives them for help text written by hand.
+///
+/// A `Millis` field needs no parser to be named, as `Millis` implements
+/// `ValueParserFactory`.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{Arg, Command};
+/// use millis::DurationParser;
+/// use std::time::Duration;
+///
+/// let parser = DurationParser::<Duration>::new().min(100).max(60_000);
+/// let timeout = Arg::new("timeout").long("timeout").help("Request timeout");
+/// let cmd = Command::new("app").arg(parser.configure(timeout));
+///
+/// let matches = cmd.clone().try_get_matches_from(["app", "--timeout", "1.5s"]).unwrap();
+/// assert_eq!(matches.get_one::<Duration>("timeout"), Some(&Duration::from_millis(1500)));
+///
+/// let err = cmd.try_get_matches_from(["app", "--timeout", "2m"]).unwrap_err();
+/// assert!(err.to_string().contains("must be at most 1m"));
+/// ```
+#[derive(Debug)]
+pub struct DurationParser<T> {
+    min: Option<i64>,
+    max: Option<i64>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Value> DurationParser<T> {
+    /// Create a parser without bounds.
+    pub fn new() -> DurationParser<T> {
+        DurationParser {
+            min: None,
+            max: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Reject durations shorter than `ms` milliseconds.
+    pub fn min(mut self, ms: i64) -> DurationParser<T> {
+        self.min = Some(ms);
+        self
+    }
+
+    /// Reject durations longer than `ms` milliseconds.
+    pub fn max(mut self, ms: i64) -> DurationParser<T> {
+        self.max = Some(ms);
+        self
+    }
+
+    /// Example values within the bounds, such as `["500ms", "30s"]`, for help
+    /// text. Any duration within the bounds is accepted, not only these.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use millis::DurationParser;
+    ///
+    /// let parser = DurationParser::<i64>::new().max(300_000);
+    /// assert_eq!(parser.examples(), ["500ms", "30s", "5m"]);
+    /// ```
+    pub fn examples(&self) -> Vec<&'static str> {
+        HINTS
+            .into_iter()
+            .filter(|hint| parse(hint).is_ok_and(|ms| self.convert(ms).is_ok()))
+            .collect()
+    }
+
+    /// Use this parser for `arg`, and append the examples to its help text,
+    /// as in "Request timeout [examples: 500ms, 30s, 5m]".
+    ///
+    /// The examples are written as a hint rather than as clap's possible
+    /// values, which `--help` would present as the only accepted values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Arg, Command};
+    /// use millis::DurationParser;
+    ///
+    /// let parser = DurationParser::<i64>::new().max(300_000);
+    /// let arg = Arg::new("timeout").long("timeout").help("Request timeout");
+    /// let mut cmd = Command::new("app").arg(parser.configure(arg));
+    /// let help = cmd.render_help().to_string();
+    /// assert!(help.contains("Request timeout [examples: 500ms, 30s, 5m]"));
+    /// ```
+    pub fn configure(self, arg: Arg) -> Arg {
+        let examples = self.examples();
+        let arg = arg.value_parser(self);
+        if examples.is_empty() {
+            return arg;
+        }
+
+        let hint = format!("[examples: {}]", examples.join(", "));
+        let with_hint = |help: Option<&StyledStr>| match help {
+            Some(help) => format!("{} {}", help, hint),
+            None => hint.clone(),
+        };
+        let help = with_hint(arg.get_help());
+        match arg
+            .get_long_help()
+            .map(|long_help| with_hint(Some(long_help)))
+        {
+            Some(long_help) => arg.help(help).long_help(long_help),
+            None => arg.help(help),
+        }
+    }
+
+    /// Convert milliseconds, checking the bounds
+    fn convert(&self, ms: i64) -> Result<T, String> {
+        if let Some(min) = self.min.filter(|&min| ms < min) {
+            return Err(format!("must be at least {}", describe(min)));
+        }
+        if let Some(max) = self.max.filter(|&max| ms > max) {
+            return Err(format!("must be at most {}", describe(max)));
+        }
+        T::from_millis(ms)
+    }
+}
+
+/// Show a bound exactly, with a unit even when it is plain milliseconds
+fn describe(ms: i64) -> String {
+    let text = format_exact(ms);
+    if text.ends_with(|c: char| c.is_ascii_digit()) {
+        format!("{}ms", text)
+    } else {
+        text
+    }
+}
+
+impl<T: Value> Default for DurationParser<T> {
+    fn default() -> DurationParser<T> {
+        DurationParser::new()
+    }
+}
+
+// Derived `Clone` would require `T: Clone`
+impl<T> Clone for DurationParser<T> {
+    fn clone(&self) -> DurationParser<T> {
+        DurationParser {
+            min: self.min,
+            max: self.max,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Value> TypedValueParser for DurationParser<T> {
+    type Value = T;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<T, Error> {
+        let invalid = |reason: String, tip: bool| {
+            let arg = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+            let mut message = format!(
+                "invalid value '{}' for '{}': {}\n",
+                value.to_string_lossy(),
+                arg,
+                reason
+            );
+            if tip {
+                message.push_str("\n  tip: use a number with a unit");
+                if let [rest @ .., last] = self.examples().as_slice() {
+                    message.push_str(", such as ");
+                    if !rest.is_empty() {
+                        message.push_str(&rest.join(", "));
+                        message.push_str(" or ");
+                    }
+                    message.push_str(last);
+                }
+                message.push('\n');
+            }
+            Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+        };
+
+        let text = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        let ms = parse(text.trim()).map_err(|e| invalid(e, true))?;
+        self.convert(ms).map_err(|e| invalid(e, false))
+    }
+}
+
+impl ValueParserFactory for Millis {
+    type Parser = DurationParser<Millis>;
+
+    fn value_parser() -> DurationParser<Millis> {
+        DurationParser::new()
+    }
+}
@@ -0,0 +1,273 @@
+//! Exact decimal rendering of ratios.
+
+use crate::unicode::{FRACTION_DIGITS, split_number};
+use crate::{Precision, RoundingMode, Unit};
+use std::fmt::{self, Write};
+
+// Most digits rendered after the decimal point
+const MAX_DECIMALS: i32 = 20;
+
+// Room for a sign, the 39 digits of a `u128`, a point and the decimals
+const CAPACITY: usize = 64;
+
+/// A rendered number, kept on the stack so formatting does not allocate
+#[derive(Clone, Copy)]
+pub(crate) struct Decimal {
+    buf: [u8; CAPACITY],
+    len: usize,
+}
+
+impl Decimal {
+    /// Render any displayable value, such as an integer
+    pub(crate) fn of(value: impl fmt::Display) -> Decimal {
+        let mut text = Decimal::default();
+        // Numbers always fit in `CAPACITY`
+        let _ = write!(text, "{}", value);
+        text
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // Only whole `&str`s are ever written to the buffer
+        std::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Default for Decimal {
+    fn default() -> Self {
+        Decimal {
+            buf: [0; CAPACITY],
+            len: 0,
+        }
+    }
+}
+
+impl Write for Decimal {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > CAPACITY {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Round the magnitude of `num / den` to an integer. `negative` gives the sign
+/// of the ratio, which decides the direction of `Floor` and `Ceil`.
+pub(crate) fn round_div(num: u128, den: u128, negative: bool, mode: RoundingMode) -> u128 {
+    let (q, r) = (num / den, num % den);
+    let round_up = match mode {
+        _ if r == 0 => false,
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceil => !negative,
+        RoundingMode::Truncate => false,
+        RoundingMode::HalfEven => r > den - r || (r == den - r && q % 2 == 1),
+        RoundingMode::HalfUp => r >= den - r,
+    };
+    if round_up { q + 1 } else { q }
+}
+
+// Denominator of the fractional milliseconds kept while parsing. Divisible by
+// every vulgar fraction (lcm 2520) and by 10^27, for 24 decimal digits of
+// microseconds.
+const PARSE_SCALE: u128 = 2520 * 10u128.pow(27);
+
+/// A sum of parsed numbers of units, kept exactly as whole milliseconds and a
+/// fraction of `PARSE_SCALE`
+#[derive(Default)]
+pub(crate) struct ExactSum {
+    whole: u128,
+    fraction: u128,
+    /// Whether digits dropped after `FRACTION_DIGITS` make the sum slightly
+    /// larger
+    sticky: bool,
+}
+
+impl ExactSum {
+    /// Add `value`, an unsigned matched number, of `unit`s. Returns `None` if
+    /// the sum overflows or `value` is not a number.
+    pub(crate) fn add(&mut self, value: &str, unit: Unit) -> Option<()> {
+        let number = split_number(value)?;
+        let (num, den) = unit.ratio();
+        self.add_ratio(number.whole?.checked_mul(num)?, den)?;
+        // Cannot overflow: below 10^24 * 2^45
+        self.add_ratio(number.decimals * num, den * 10u128.pow(FRACTION_DIGITS))?;
+        if let Some((n, d)) = number.fraction {
+            self.add_ratio(u128::from(n) * num, den * u128::from(d))?;
+        }
+        self.sticky |= number.sticky;
+        Some(())
+    }
+
+    /// Add `num / den` milliseconds, where `den` divides `PARSE_SCALE`
+    fn add_ratio(&mut self, num: u128, den: u128) -> Option<()> {
+        self.whole = self.whole.checked_add(num / den)?;
+        self.fraction += num % den * (PARSE_SCALE / den);
+        if self.fraction >= PARSE_SCALE {
+            self.fraction -= PARSE_SCALE;
+            self.whole = self.whole.checked_add(1)?;
+        }
+        Some(())
+    }
+
+    /// Round the sum to multiples of `1 / per_ms` milliseconds, with the sign
+    /// applied: whole milliseconds for a `per_ms` of 1, nanoseconds for 10^6.
+    /// Returns `None` if the result does not fit in an `i128`.
+    pub(crate) fn round(&self, per_ms: u128, negative: bool, mode: RoundingMode) -> Option<i128> {
+        // Cannot overflow for `per_ms` up to 10^6: the fraction is below 2^82
+        let scaled = self.fraction * per_ms;
+        let whole = self
+            .whole
+            .checked_mul(per_ms)?
+            .checked_add(scaled / PARSE_SCALE)?;
+        // Round the last bit of the whole part together with the fraction, so
+        // `HalfEven` sees its parity. A sticky remainder breaks any tie.
+        let parity = whole % 2;
+        let num = parity * 2 * PARSE_SCALE + scaled % PARSE_SCALE * 2 + u128::from(self.sticky);
+        let rounded = round_div(num, 2 * PARSE_SCALE, negative, mode);
+        let magnitude = (whole - parity).checked_add(rounded)?;
+        if negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+}
+
+/// How to round and render a ratio
+#[derive(Clone, Copy)]
+pub(crate) struct Rendering {
+    pub(crate) precision: Option<Precision>,
+    pub(crate) trim: bool,
+    pub(crate) rounding: RoundingMode,
+    pub(crate) negative: bool,
+}
+
+/// Render the magnitude of the ratio `num / den` as a decimal number.
+///
+/// Without a precision the ratio is rounded to an integer. With `trim`,
+/// trailing zeros after the decimal point (and the point itself) are dropped.
+pub(crate) fn render(num: u128, den: u128, how: Rendering) -> Decimal {
+    let mut text = match how.precision {
+        None => Decimal::of(round_div(num, den, how.negative, how.rounding)),
+        Some(Precision::Decimals(digits)) => fixed(num, den, i32::from(digits), how).0,
+        Some(Precision::Significant(digits)) => {
+            let digits = i32::from(digits.max(1));
+            let exponent = exponent(num, den);
+            let (text, rounded) = fixed(num, den, digits - exponent - 1, how);
+            // Rounding up may carry into a new leading digit, as in 9.96 -> 10.0
+            let carried = exponent >= -1
+                && 10u128
+                    .checked_pow((exponent + 1) as u32)
+                    .is_some_and(|next| rounded >= next);
+            if carried {
+                fixed(num, den, digits - exponent - 2, how).0
+            } else {
+                text
+            }
+        }
+    };
+
+    if how.trim && text.as_str().contains('.') {
+        text.len = text
+            .as_str()
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .len();
+    }
+    text
+}
+
+/// Render the magnitude of `num * 1000` like `render()`, for values where the
+/// product does not fit in a `u128`.
+///
+/// The product is whole, so its decimals are zeros, and rounding it to a power
+/// of ten above the thousands rounds `num` to a power of ten three places lower.
+pub(crate) fn render_thousandfold(num: u128, how: Rendering) -> Decimal {
+    let decimals = match how.precision {
+        None => 0,
+        Some(Precision::Decimals(digits)) => i32::from(digits),
+        // The product has three more digits before the point than `num`
+        Some(Precision::Significant(digits)) => {
+            i32::from(digits.max(1)) - (exponent(num, 1) + 3) - 1
+        }
+    };
+    let whole = if decimals < -3 {
+        fixed(num, 1, decimals + 3, how).1
+    } else {
+        num
+    };
+
+    // Room for the 40 digits of a rounded `num`, three zeros, a point and
+    // `MAX_DECIMALS` zeros
+    let mut text = Decimal::default();
+    let _ = write!(text, "{}000", whole);
+    if decimals > 0 && !how.trim {
+        let _ = write!(
+            text,
+            ".{:0width$}",
+            0,
+            width = decimals.min(MAX_DECIMALS) as usize
+        );
+    }
+    text
+}
+
+/// Render `num / den` rounded to `decimals` digits after the point, or to a
+/// multiple of a power of ten when `decimals` is negative. Also returns the
+/// rounded integer part.
+fn fixed(num: u128, den: u128, decimals: i32, how: Rendering) -> (Decimal, u128) {
+    if decimals <= 0 {
+        let scale = 10u128.checked_pow(decimals.unsigned_abs());
+        let rounded = match scale.and_then(|scale| den.checked_mul(scale)) {
+            Some(den) => {
+                round_div(num, den, how.negative, how.rounding).saturating_mul(scale.unwrap_or(1))
+            }
+            None => 0,
+        };
+        return (Decimal::of(rounded), rounded);
+    }
+
+    let decimals = decimals.min(MAX_DECIMALS) as u32;
+    let scale = 10u128.pow(decimals);
+    let (mut whole, rest) = (num / den, num % den);
+    let mut fraction = round_div(rest * scale, den, how.negative, how.rounding);
+    if fraction == scale {
+        whole += 1;
+        fraction = 0;
+    }
+    let mut text = Decimal::default();
+    let _ = write!(
+        text,
+        "{}.{:0width$}",
+        whole,
+        fraction,
+        width = decimals as usize
+    );
+    (text, whole)
+}
+
+/// The decimal exponent `e` of `num / den`, with `10^e <= num / den < 10^(e + 1)`
+fn exponent(num: u128, den: u128) -> i32 {
+    let whole = num / den;
+    if whole > 0 {
+        return whole.ilog10() as i32;
+    }
+    if num == 0 {
+        return 0;
+    }
+
+    let (mut rest, mut exponent) = (num, 0);
+    while rest < den && exponent > -MAX_DECIMALS {
+        rest *= 10;
+        exponent -= 1;
+    }
+    exponent
+}
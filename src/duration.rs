@@ -0,0 +1,167 @@
+//! The `Millis` newtype for durations in milliseconds.
+
+use crate::{HumanDuration, ToMillis, format, parse};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// A signed duration in milliseconds.
+///
+/// Unlike a bare `i64`, a `Millis` can only be added to or subtracted from
+/// another `Millis`, and scaled by an `i64`, so it is not mixed up with
+/// timestamps or counts. It parses with `parse()` and displays with
+/// `format()`, including the `{:#}` and `{:.N}` flags of [`HumanDuration`].
+///
+/// `ms()` formats a `Millis`, but parsing a string with `ms()` still returns
+/// an `i64`: its return type follows from the input, and changing it would
+/// break every existing caller. Parse with `str::parse()` instead.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{ms, Millis};
+/// use std::time::Duration;
+///
+/// let timeout: Millis = "1.5h".parse().unwrap();
+/// assert_eq!(timeout, Millis(5400000));
+/// assert_eq!(timeout.to_string(), "2h");
+/// assert_eq!(format!("{:#.1}", timeout), "1.5 hours");
+///
+/// let total: Millis = [timeout, Millis(600000)].into_iter().sum::<Millis>() * 2;
+/// assert_eq!(i64::from(total), 12000000);
+///
+/// let retry: Millis = "30s".parse().unwrap();
+/// assert_eq!(ms(retry).unwrap(), "30s");
+/// assert_eq!(Duration::try_from(retry).unwrap(), Duration::from_secs(30));
+/// assert!(Duration::try_from(-retry).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Millis(pub i64);
+
+impl FromStr for Millis {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Millis, String> {
+        parse(s).map(Millis)
+    }
+}
+
+impl fmt::Display for Millis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&HumanDuration::from(self.0), f)
+    }
+}
+
+/// Implementation for Millis - converts milliseconds to formatted string
+impl ToMillis for Millis {
+    type Output = Result<String, String>;
+
+    fn to_millis(self) -> Result<String, String> {
+        Ok(format(self.0, None))
+    }
+}
+
+impl From<i64> for Millis {
+    fn from(ms: i64) -> Millis {
+        Millis(ms)
+    }
+}
+
+impl From<Millis> for i64 {
+    fn from(ms: Millis) -> i64 {
+        ms.0
+    }
+}
+
+impl From<Millis> for HumanDuration {
+    fn from(ms: Millis) -> HumanDuration {
+        HumanDuration::from(ms.0)
+    }
+}
+
+/// Sub-millisecond parts of the `Duration` are dropped.
+impl TryFrom<Duration> for Millis {
+    type Error = String;
+
+    fn try_from(duration: Duration) -> Result<Millis, String> {
+        i64::try_from(duration.as_millis())
+            .map(Millis)
+            .map_err(|_| format!("Duration out of range. value={:?}", duration))
+    }
+}
+
+impl TryFrom<Millis> for Duration {
+    type Error = String;
+
+    fn try_from(ms: Millis) -> Result<Duration, String> {
+        u64::try_from(ms.0)
+            .map(Duration::from_millis)
+            .map_err(|_| format!("Negative duration. value={:?}", ms))
+    }
+}
+
+impl Add for Millis {
+    type Output = Millis;
+
+    fn add(self, rhs: Millis) -> Millis {
+        Millis(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Millis {
+    fn add_assign(&mut self, rhs: Millis) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Millis {
+    type Output = Millis;
+
+    fn sub(self, rhs: Millis) -> Millis {
+        Millis(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Millis {
+    fn sub_assign(&mut self, rhs: Millis) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Mul<i64> for Millis {
+    type Output = Millis;
+
+    fn mul(self, rhs: i64) -> Millis {
+        Millis(self.0 * rhs)
+    }
+}
+
+impl Mul<Millis> for i64 {
+    type Output = Millis;
+
+    fn mul(self, rhs: Millis) -> Millis {
+        Millis(self * rhs.0)
+    }
+}
+
+impl Neg for Millis {
+    type Output = Millis;
+
+    fn neg(self) -> Millis {
+        Millis(-self.0)
+    }
+}
+
+impl Sum for Millis {
+    fn sum<I: Iterator<Item = Millis>>(iter: I) -> Millis {
+        iter.fold(Millis(0), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Millis> for Millis {
+    fn sum<I: Iterator<Item = &'a Millis>>(iter: I) -> Millis {
+        iter.copied().sum()
+    }
+}
@@ -0,0 +1,298 @@
+//! Durations read from environment variables.
+
+use crate::decimal::ExactSum;
+use crate::{NUMBER, RoundingMode, Unit, format_exact, parse};
+use regex::Regex;
+use std::env;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// Options for `env_duration_with()`.
+///
+/// New options may be added in minor releases, so build them with
+/// [`EnvOptions::new()`] and the setters rather than a struct expression.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct EnvOptions {
+    /// Reject durations shorter than this many milliseconds
+    pub min: Option<i64>,
+    /// Reject durations longer than this many milliseconds
+    pub max: Option<i64>,
+    /// Unit of a bare number such as "30", for variables that predate units.
+    /// Bare numbers are milliseconds when this is `None`.
+    pub bare_unit: Option<Unit>,
+}
+
+impl EnvOptions {
+    /// Create the default options.
+    pub const fn new() -> EnvOptions {
+        EnvOptions {
+            min: None,
+            max: None,
+            bare_unit: None,
+        }
+    }
+
+    /// Set [`min`](EnvOptions::min).
+    pub const fn min(mut self, min: i64) -> EnvOptions {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set [`max`](EnvOptions::max).
+    pub const fn max(mut self, max: i64) -> EnvOptions {
+        self.max = Some(max);
+        self
+    }
+
+    /// Set [`bare_unit`](EnvOptions::bare_unit).
+    pub const fn bare_unit(mut self, bare_unit: Unit) -> EnvOptions {
+        self.bare_unit = Some(bare_unit);
+        self
+    }
+}
+
+impl Default for EnvOptions {
+    fn default() -> EnvOptions {
+        EnvOptions::new()
+    }
+}
+
+/// An error reading a duration from an environment variable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvError {
+    /// The variable is set but is not valid Unicode
+    NotUnicode {
+        /// Name of the variable
+        name: String,
+    },
+    /// The value is not a duration
+    Invalid {
+        /// Name of the variable
+        name: String,
+        /// Value of the variable
+        value: String,
+        /// Why the value could not be parsed
+        reason: String,
+    },
+    /// The value is shorter than the minimum
+    TooShort {
+        /// Name of the variable
+        name: String,
+        /// Value of the variable
+        value: String,
+        /// The minimum in milliseconds
+        min: i64,
+    },
+    /// The value is longer than the maximum
+    TooLong {
+        /// Name of the variable
+        name: String,
+        /// Value of the variable
+        value: String,
+        /// The maximum in milliseconds
+        max: i64,
+    },
+}
+
+impl EnvError {
+    /// Name of the variable the error is about
+    pub fn name(&self) -> &str {
+        match self {
+            EnvError::NotUnicode { name }
+            | EnvError::Invalid { name, .. }
+            | EnvError::TooShort { name, .. }
+            | EnvError::TooLong { name, .. } => name,
+        }
+    }
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvError::NotUnicode { name } => {
+                write!(f, "Environment variable {} is not valid Unicode", name)
+            }
+            EnvError::Invalid {
+                name,
+                value,
+                reason,
+            } => write!(
+                f,
+                "Environment variable {} is not a duration: {}. value={:?}",
+                name, reason, value
+            ),
+            EnvError::TooShort { name, value, min } => write!(
+                f,
+                "Environment variable {} must be at least {}. value={:?}",
+                name,
+                format_exact(*min),
+                value
+            ),
+            EnvError::TooLong { name, value, max } => write!(
+                f,
+                "Environment variable {} must be at most {}. value={:?}",
+                name,
+                format_exact(*max),
+                value
+            ),
+        }
+    }
+}
+
+impl Error for EnvError {}
+
+/// Read a duration in milliseconds from an environment variable, or return
+/// `default` when it is unset or empty.
+///
+/// The value is parsed with `parse()`, so `REQUEST_TIMEOUT=30s` gives 30000.
+/// Use `env_duration_with()` for bounds or a unit for bare numbers.
+///
+/// # Errors
+///
+/// Returns an [`EnvError`] naming the variable if its value is not valid
+/// Unicode or not a duration.
+///
+/// # Examples
+///
+/// ```
+/// use millis::env_duration;
+///
+/// // SAFETY: no other thread reads the environment in this example
+/// unsafe { std::env::set_var("EXAMPLE_TIMEOUT", "30s") };
+/// assert_eq!(env_duration("EXAMPLE_TIMEOUT", 5000).unwrap(), 30000);
+/// assert_eq!(env_duration("EXAMPLE_UNSET", 5000).unwrap(), 5000);
+///
+/// unsafe { std::env::set_var("EXAMPLE_TIMEOUT", "soon") };
+/// let err = env_duration("EXAMPLE_TIMEOUT", 5000).unwrap_err();
+/// assert_eq!(err.name(), "EXAMPLE_TIMEOUT");
+/// ```
+pub fn env_duration(name: &str, default: i64) -> Result<i64, EnvError> {
+    env_duration_with(name, default, None)
+}
+
+/// Read a duration in milliseconds from an environment variable with the
+/// given options, or return `default` when it is unset or empty.
+///
+/// The default is returned as given, without checking the bounds.
+///
+/// # Errors
+///
+/// Returns an [`EnvError`] naming the variable if its value is not valid
+/// Unicode, not a duration, or outside the bounds.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{env_duration_with, EnvError, EnvOptions, Unit};
+///
+/// let options = EnvOptions::new().min(1000).max(300000).bare_unit(Unit::Seconds);
+///
+/// // SAFETY: no other thread reads the environment in this example
+/// unsafe { std::env::set_var("EXAMPLE_RETRY", "45") };
+/// assert_eq!(env_duration_with("EXAMPLE_RETRY", 10000, Some(options)).unwrap(), 45000);
+///
+/// unsafe { std::env::set_var("EXAMPLE_RETRY", "1h") };
+/// let err = env_duration_with("EXAMPLE_RETRY", 10000, Some(options)).unwrap_err();
+/// assert!(matches!(err, EnvError::TooLong { max: 300000, .. }));
+/// assert_eq!(
+///     err.to_string(),
+///     "Environment variable EXAMPLE_RETRY must be at most 5m. value=\"1h\""
+/// );
+/// ```
+pub fn env_duration_with(
+    name: &str,
+    default: i64,
+    options: Option<EnvOptions>,
+) -> Result<i64, EnvError> {
+    env_value_duration(name, env::var_os(name), default, options)
+}
+
+/// Read a duration in milliseconds from the value of an environment variable,
+/// as `env_duration_with()` does, or return `default` when it is `None` or
+/// empty.
+///
+/// This does not read the environment itself, so it suits values taken from
+/// `std::env::vars_os()`, a `.env` file or a test fixture. `name` is only used
+/// in errors.
+///
+/// # Errors
+///
+/// Returns an [`EnvError`] naming the variable if the value is not valid
+/// Unicode, not a duration, or outside the bounds.
+///
+/// # Examples
+///
+/// ```
+/// use millis::env_value_duration;
+///
+/// let value = Some("30s".into());
+/// assert_eq!(env_value_duration("TIMEOUT", value, 5000, None).unwrap(), 30000);
+/// assert_eq!(env_value_duration("TIMEOUT", None, 5000, None).unwrap(), 5000);
+/// ```
+pub fn env_value_duration(
+    name: &str,
+    value: Option<OsString>,
+    default: i64,
+    options: Option<EnvOptions>,
+) -> Result<i64, EnvError> {
+    let opts = options.unwrap_or_default();
+    let value = match value.map(OsString::into_string) {
+        Some(Ok(value)) if !value.trim().is_empty() => value,
+        Some(Ok(_)) | None => return Ok(default),
+        Some(Err(_)) => {
+            return Err(EnvError::NotUnicode {
+                name: name.to_string(),
+            });
+        }
+    };
+
+    let text = value.trim();
+    let ms = match (opts.bare_unit, bare_number(text)) {
+        (Some(unit), Some((negative, number))) => in_unit(number, unit, negative)
+            .ok_or_else(|| format!("Duration out of range. value={:?}", text)),
+        _ => parse(text),
+    }
+    .map_err(|reason| EnvError::Invalid {
+        name: name.to_string(),
+        value: value.clone(),
+        reason,
+    })?;
+
+    if let Some(min) = opts.min.filter(|&min| ms < min) {
+        return Err(EnvError::TooShort {
+            name: name.to_string(),
+            value,
+            min,
+        });
+    }
+    if let Some(max) = opts.max.filter(|&max| ms > max) {
+        return Err(EnvError::TooLong {
+            name: name.to_string(),
+            value,
+            max,
+        });
+    }
+    Ok(ms)
+}
+
+/// Split a number without a unit, such as "30" or "-1.5", into its sign and
+/// unsigned number
+fn bare_number(s: &str) -> Option<(bool, &str)> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re =
+        RE.get_or_init(|| Regex::new(&format!(r"^(?P<sign>[-−]?)(?P<number>{NUMBER})$")).unwrap());
+    let caps = re.captures(s)?;
+    Some((!caps["sign"].is_empty(), caps.name("number")?.as_str()))
+}
+
+/// Milliseconds in `number` of `unit`s, rounded as `parse()` does. Returns
+/// `None` beyond the i64 range.
+fn in_unit(number: &str, unit: Unit, negative: bool) -> Option<i64> {
+    let mut sum = ExactSum::default();
+    sum.add(number, unit)?;
+    let total = sum.round(1, negative, RoundingMode::default())?;
+    i64::try_from(total).ok()
+}
@@ -0,0 +1,112 @@
+//! Lossless formatting that `parse()` reads back exactly.
+
+use crate::Unit;
+
+/// Units `format_exact()` may combine, longest first.
+/// Milliseconds are always available for the remainder.
+const EXACT_UNITS: [Unit; 7] = [
+    Unit::Years,
+    Unit::Months,
+    Unit::Weeks,
+    Unit::Days,
+    Unit::Hours,
+    Unit::Minutes,
+    Unit::Seconds,
+];
+
+/// Mask of the units used with names: days, hours, minutes and seconds
+const LONG_UNITS: u32 = 0b1111;
+
+/// Format the given milliseconds as the shortest string that parses back to
+/// exactly the same value.
+///
+/// Units are combined without spaces, longest first, as in "1d1m" or "1y1d";
+/// a plain number of milliseconds is used when that is shortest.
+/// `parse(&format_exact(x)) == Ok(x)` holds for every `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{format_exact, parse};
+///
+/// assert_eq!(format_exact(5400000), "90m");
+/// assert_eq!(format_exact(86460000), "1d1m");
+/// assert_eq!(format_exact(-90000000), "-25h");
+/// assert_eq!(format_exact(1234), "1234");
+/// assert_eq!(parse("1d1m").unwrap(), 86460000);
+///
+/// assert_eq!(parse(&format_exact(i64::MIN)).unwrap(), i64::MIN);
+/// ```
+pub fn format_exact(ms: i64) -> String {
+    exact(i128::from(ms), false)
+}
+
+/// Format `ms` exactly: as the shortest string with symbols, as in "1d1m", or
+/// when `long` is set, in days, hours, minutes, seconds and milliseconds with
+/// their names, as in "1 day 1 minute"
+pub(crate) fn exact(ms: i128, long: bool) -> String {
+    let sign = if ms < 0 { "-" } else { "" };
+    let ms_abs = ms.unsigned_abs();
+    if long {
+        return format!("{}{}", sign, decompose(ms_abs, LONG_UNITS, true).0);
+    }
+
+    // Try every combination of units and keep the shortest result, preferring
+    // fewer parts, then longer units
+    let mut best: Option<(String, usize)> = None;
+    for mask in (0..1u32 << EXACT_UNITS.len()).rev() {
+        let (text, parts) = decompose(ms_abs, mask, false);
+        let shorter = best
+            .as_ref()
+            .is_none_or(|(b, b_parts)| (text.len(), parts) < (b.len(), *b_parts));
+        if shorter {
+            best = Some((text, parts));
+        }
+    }
+    let (text, _) = best.unwrap_or_default();
+    format!("{}{}", sign, text)
+}
+
+/// Greedily split `ms_abs` into the units selected by `mask`, where the highest
+/// bit selects years. Returns the text and the number of parts.
+fn decompose(ms_abs: u128, mask: u32, long: bool) -> (String, usize) {
+    let mut parts = Vec::new();
+    let mut rest = ms_abs;
+    for (i, unit) in EXACT_UNITS.iter().enumerate() {
+        if mask & (1 << (EXACT_UNITS.len() - 1 - i)) == 0 {
+            continue;
+        }
+        let (size, _) = unit.ratio();
+        if rest >= size {
+            parts.push((rest / size, *unit));
+            rest %= size;
+        }
+    }
+    if rest > 0 || (long && parts.is_empty()) {
+        parts.push((rest, Unit::Milliseconds));
+    }
+
+    let count = parts.len().max(1);
+    if long {
+        let words: Vec<String> = parts
+            .iter()
+            .map(|&(value, unit)| {
+                let (singular, plural) = unit.names();
+                format!("{} {}", value, if value == 1 { singular } else { plural })
+            })
+            .collect();
+        return (words.join(" "), count);
+    }
+    match parts.as_slice() {
+        [] => ("0".to_string(), 1),
+        [(value, Unit::Milliseconds)] => (value.to_string(), 1),
+        _ => {
+            let text = parts
+                .iter()
+                // Every exact unit has a symbol
+                .map(|&(value, unit)| format!("{}{}", value, unit.symbol().unwrap_or_default()))
+                .collect();
+            (text, count)
+        }
+    }
+}
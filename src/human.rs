@@ -0,0 +1,68 @@
+//! A `Display` wrapper for formatting durations without allocating.
+
+use crate::{Options, Precision, format, format_into};
+use std::fmt::{self, Alignment, Write};
+
+/// Milliseconds with `format()` options, formatted through `Display`.
+///
+/// Writing a `HumanDuration` with `write!` or `{}` goes straight to the
+/// output, without building a `String` first. The format flags adjust the
+/// options: `{:#}` selects the long style, `{:.N}` shows `N` decimals, and a
+/// width such as `{:>8}` pads the result.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{HumanDuration, Options, Style};
+///
+/// let d = HumanDuration(5400000, Options::default());
+/// assert_eq!(d.to_string(), "2h");
+/// assert_eq!(format!("{:#}", d), "2 hours");
+/// assert_eq!(format!("{:.1}", d), "1.5h");
+/// assert_eq!(format!("{:#.2}", d), "1.50 hours");
+/// assert_eq!(format!("[{:>5}]", d), "[   2h]");
+///
+/// let short = HumanDuration(7200000, Options::new().style(Style::Short));
+/// assert_eq!(format!("took {}", short), "took 2 hrs");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HumanDuration(pub i64, pub Options);
+
+impl From<i64> for HumanDuration {
+    fn from(ms: i64) -> Self {
+        HumanDuration(ms, Options::default())
+    }
+}
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut opts = self.1;
+        if f.alternate() {
+            opts.long = true;
+        }
+        if let Some(decimals) = f.precision() {
+            opts.precision = Some(Precision::Decimals(decimals.min(255) as u8));
+        }
+
+        let Some(width) = f.width() else {
+            return format_into(f, self.0, Some(opts));
+        };
+        // Padding needs the length up front; `Formatter::pad` would also treat
+        // the precision as a maximum width
+        let text = format(self.0, Some(opts));
+        let padding = width.saturating_sub(text.chars().count());
+        let (before, after) = match f.align() {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (0, padding),
+        };
+        for _ in 0..before {
+            f.write_char(f.fill())?;
+        }
+        f.write_str(&text)?;
+        for _ in 0..after {
+            f.write_char(f.fill())?;
+        }
+        Ok(())
+    }
+}
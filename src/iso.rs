@@ -0,0 +1,97 @@
+//! ISO 8601 durations such as "PT1H30M".
+
+use crate::decimal::ExactSum;
+use crate::{RoundingMode, Unit};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Components of an ISO 8601 duration, in order, as capture names and units.
+/// The first four come before the `T`.
+const DESIGNATORS: [(&str, Unit); 7] = [
+    ("years", Unit::Years),
+    ("months", Unit::Months),
+    ("weeks", Unit::Weeks),
+    ("days", Unit::Days),
+    ("hours", Unit::Hours),
+    ("minutes", Unit::Minutes),
+    ("seconds", Unit::Seconds),
+];
+
+/// Whether `s` looks like an ISO 8601 duration rather than a time string
+pub(crate) fn is_iso(s: &str) -> bool {
+    s.trim_start_matches(['-', '+']).starts_with('P')
+}
+
+/// Parse an ISO 8601 duration such as "P1DT2H" or "-PT0.5S" into milliseconds.
+///
+/// Years and months have the fixed lengths used by `parse()`. A decimal comma
+/// is accepted, and the result is rounded half up.
+pub(crate) fn parse_iso(s: &str) -> Result<i128, String> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| {
+        let n = r"\d+(?:[.,]\d+)?";
+        Regex::new(&format!(
+            r"^(?P<sign>[-+]?)P(?:(?P<years>{n})Y)?(?:(?P<months>{n})M)?(?:(?P<weeks>{n})W)?(?:(?P<days>{n})D)?(?P<time>T(?:(?P<hours>{n})H)?(?:(?P<minutes>{n})M)?(?:(?P<seconds>{n})S)?)?$"
+        ))
+        .unwrap()
+    });
+    let invalid = || format!("Invalid ISO 8601 duration. value={:?}", s);
+    let caps = re.captures(s).ok_or_else(invalid)?;
+
+    let mut sum = ExactSum::default();
+    let mut date_parts = 0;
+    let mut time_parts = 0;
+    for (i, (name, unit)) in DESIGNATORS.iter().enumerate() {
+        let Some(value) = caps.name(name) else {
+            continue;
+        };
+        if i < 4 {
+            date_parts += 1;
+        } else {
+            time_parts += 1;
+        }
+        sum.add(&value.as_str().replace(',', "."), *unit)
+            .ok_or_else(|| format!("Duration out of range. value={:?}", s))?;
+    }
+    // "P" and "PT" alone, and a "T" without a time part, are not durations
+    if date_parts + time_parts == 0 || (caps.name("time").is_some() && time_parts == 0) {
+        return Err(invalid());
+    }
+
+    let negative = &caps["sign"] == "-";
+    sum.round(1, negative, RoundingMode::HalfUp)
+        .ok_or_else(|| format!("Duration out of range. value={:?}", s))
+}
+
+/// Format milliseconds as an ISO 8601 duration in days and time, such as
+/// "P1DT2H" or "-PT0.5S"
+pub(crate) fn format_iso(ms: i128) -> String {
+    let sign = if ms < 0 { "-" } else { "" };
+    let ms_abs = ms.unsigned_abs();
+    let days = ms_abs / 86_400_000;
+    let hours = ms_abs / 3_600_000 % 24;
+    let minutes = ms_abs / 60_000 % 60;
+    let seconds = ms_abs / 1000 % 60;
+    let millis = ms_abs % 1000;
+
+    let mut out = format!("{}P", sign);
+    if days > 0 {
+        out.push_str(&format!("{}D", days));
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || millis > 0 || days == 0 {
+        out.push('T');
+    }
+    if hours > 0 {
+        out.push_str(&format!("{}H", hours));
+    }
+    if minutes > 0 {
+        out.push_str(&format!("{}M", minutes));
+    }
+    if millis > 0 {
+        let fraction = format!("{:03}", millis);
+        out.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+    } else if seconds > 0 || ms_abs == 0 {
+        out.push_str(&format!("{}S", seconds));
+    }
+    out
+}
@@ -0,0 +1,215 @@
+//! `jiff` support, behind the `jiff` feature.
+
+use crate::decimal::ExactSum;
+use crate::unicode::split_number;
+use crate::{Options, ParseOptions, RoundingMode, Style, Unit, format, narrow_symbol, parse_parts};
+use ::jiff::Span;
+
+/// Units a `Span` keeps, largest first, with their length in nanoseconds for
+/// the ones that have a fixed length
+const SPAN_UNITS: [(Unit, Option<i64>); 9] = [
+    (Unit::Years, None),
+    (Unit::Months, None),
+    (Unit::Weeks, None),
+    (Unit::Days, None),
+    (Unit::Hours, Some(3_600_000_000_000)),
+    (Unit::Minutes, Some(60_000_000_000)),
+    (Unit::Seconds, Some(1_000_000_000)),
+    (Unit::Milliseconds, Some(1_000_000)),
+    (Unit::Microseconds, Some(1_000)),
+];
+
+/// The field of `SPAN_UNITS` a parsed unit goes in, and how many of that
+/// field one of the unit is
+fn span_field(unit: Unit) -> (usize, i64) {
+    match unit {
+        Unit::Millennia => (0, 1000),
+        Unit::Centuries => (0, 100),
+        Unit::Decades => (0, 10),
+        Unit::Years => (0, 1),
+        Unit::Months => (1, 1),
+        Unit::Fortnights => (2, 2),
+        Unit::Weeks => (2, 1),
+        Unit::Days => (3, 1),
+        Unit::Hours => (4, 1),
+        Unit::Minutes => (5, 1),
+        Unit::Seconds => (6, 1),
+        Unit::Milliseconds => (7, 1),
+        Unit::Microseconds => (8, 1),
+    }
+}
+
+/// Parse the given string into a `jiff::Span`, keeping the units as written.
+///
+/// Unlike `parse()`, "1 month" stays one calendar month and "90m" stays 90
+/// minutes, so jiff can apply the span to a date. Decades, centuries and
+/// millennia become years, and fortnights become weeks. Days and longer units
+/// must be whole numbers; a fraction of a shorter unit is carried into the
+/// units below it, so "1.5h" is 1 hour 30 minutes.
+///
+/// # Errors
+///
+/// Returns an error for invalid input, a fractional day or longer unit, or a
+/// value outside the range of `Span`.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::{civil::date, ToSpan};
+/// use millis::parse_span;
+///
+/// let span = parse_span("1mo 2d").unwrap();
+/// assert_eq!(span.fieldwise(), 1.month().days(2));
+/// assert_eq!(parse_span("1.5h").unwrap().fieldwise(), 1.hour().minutes(30));
+/// assert_eq!(parse_span("-90m").unwrap().fieldwise(), (-90).minutes());
+///
+/// // One calendar month from January 31 is the end of February
+/// assert_eq!(date(2024, 1, 31).checked_add(parse_span("1mo").unwrap()).unwrap(), date(2024, 2, 29));
+///
+/// assert!(parse_span("1.5 months").is_err());
+/// ```
+pub fn parse_span(s: &str) -> Result<Span, String> {
+    let opts = ParseOptions::default();
+    let parsed = parse_parts(s, &opts)?;
+    let out_of_range = || format!("Duration out of range. value={:?}", s);
+
+    let mut fields = [0i64; SPAN_UNITS.len()];
+    // Fractional parts, in full and as the nanoseconds of their whole parts
+    let mut fractional = ExactSum::default();
+    let mut fractional_whole: i128 = 0;
+    for &(value, unit) in &parsed.parts {
+        let (field, per_unit) = span_field(unit);
+        let number = split_number(value).ok_or_else(out_of_range)?;
+        let whole = number
+            .whole
+            .and_then(|whole| i64::try_from(whole).ok())
+            .and_then(|whole| whole.checked_mul(per_unit))
+            .ok_or_else(out_of_range)?;
+        fields[field] = fields[field].checked_add(whole).ok_or_else(out_of_range)?;
+
+        if number.decimals == 0 && !number.sticky && number.fraction.is_none() {
+            continue;
+        }
+        let Some(nanos) = SPAN_UNITS[field].1 else {
+            return Err(format!(
+                "Days and longer units must be whole numbers. value={:?}",
+                s
+            ));
+        };
+        fractional.add(value, unit).ok_or_else(out_of_range)?;
+        fractional_whole += i128::from(whole) * i128::from(nanos);
+    }
+
+    // Carry the fractions into the fixed units, down to nanoseconds
+    let mut rest = fractional
+        .round(1_000_000, false, RoundingMode::HalfUp)
+        .ok_or_else(out_of_range)?
+        - fractional_whole;
+    for (field, &(_, nanos)) in SPAN_UNITS.iter().enumerate() {
+        if let Some(nanos) = nanos {
+            let carried = i64::try_from(rest / i128::from(nanos)).map_err(|_| out_of_range())?;
+            fields[field] = fields[field]
+                .checked_add(carried)
+                .ok_or_else(out_of_range)?;
+            rest %= i128::from(nanos);
+        }
+    }
+
+    let span = Span::new()
+        .try_years(fields[0])
+        .and_then(|span| span.try_months(fields[1]))
+        .and_then(|span| span.try_weeks(fields[2]))
+        .and_then(|span| span.try_days(fields[3]))
+        .and_then(|span| span.try_hours(fields[4]))
+        .and_then(|span| span.try_minutes(fields[5]))
+        .and_then(|span| span.try_seconds(fields[6]))
+        .and_then(|span| span.try_milliseconds(fields[7]))
+        .and_then(|span| span.try_microseconds(fields[8]))
+        .and_then(|span| span.try_nanoseconds(rest as i64))
+        .map_err(|_| out_of_range())?;
+    Ok(if parsed.negative { span.negate() } else { span })
+}
+
+/// Format a `jiff::Span` in the style of the given options, keeping its units.
+///
+/// Every non-zero unit of the span is shown, largest first: joined without
+/// spaces in the narrow style ("1mo2d"), and with spaces in the short and long
+/// styles ("1 month 2 days"). The style is taken from the options, and the
+/// narrow style follows `m_policy` and `case_sensitive` as `format()` does, so
+/// minutes are written "min" under `MPolicy::Reject`.
+///
+/// The result parses back with `parse_span()` to the same units, except that
+/// nanoseconds are shown as a fraction of a microsecond, so 1500 nanoseconds
+/// come back as 1 microsecond and 500 nanoseconds.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::ToSpan;
+/// use millis::{format_span, Options, Style};
+///
+/// let span = 1.month().days(2).hours(3);
+/// assert_eq!(format_span(span, None), "1mo2d3h");
+/// assert_eq!(
+///     format_span(span, Some(Options::new().style(Style::Short))),
+///     "1 mth 2 days 3 hrs"
+/// );
+/// assert_eq!(
+///     format_span((-90).minutes(), Some(Options::new().long(true))),
+///     "-90 minutes"
+/// );
+/// assert_eq!(format_span(2.microseconds().nanoseconds(500), None), "2.5µs");
+/// ```
+pub fn format_span(span: Span, options: Option<Options>) -> String {
+    let opts = options.unwrap_or_default();
+    if span.is_zero() {
+        return format(0, Some(opts));
+    }
+
+    let negative = span.signum() < 0;
+    let span = span.abs();
+    let values = [
+        i64::from(span.get_years()),
+        i64::from(span.get_months()),
+        i64::from(span.get_weeks()),
+        i64::from(span.get_days()),
+        i64::from(span.get_hours()),
+        span.get_minutes(),
+        span.get_seconds(),
+        span.get_milliseconds(),
+        span.get_microseconds(),
+    ];
+    let nanos = span.get_nanoseconds();
+
+    let style = opts.effective_style();
+    let mut parts = Vec::new();
+    for (field, &(unit, _)) in SPAN_UNITS.iter().enumerate() {
+        let value = match (unit, nanos % 1000) {
+            // Nanoseconds are the only unit below microseconds
+            (Unit::Microseconds, 0) => (values[field] + nanos / 1000).to_string(),
+            (Unit::Microseconds, fraction) => {
+                let digits = format!("{:03}", fraction);
+                let micros = values[field] + nanos / 1000;
+                format!("{}.{}", micros, digits.trim_end_matches('0'))
+            }
+            _ => values[field].to_string(),
+        };
+        if value == "0" {
+            continue;
+        }
+        parts.push(match (style, narrow_symbol(unit, &opts)) {
+            (Style::Narrow, Some(symbol)) => format!("{}{}", value, symbol),
+            _ => {
+                let (singular, plural) = match style {
+                    Style::Short => unit.abbreviations(),
+                    _ => unit.names(),
+                };
+                format!("{} {}", value, if value == "1" { singular } else { plural })
+            }
+        });
+    }
+
+    let sign = if negative { "-" } else { "" };
+    let separator = if style == Style::Narrow { "" } else { " " };
+    format!("{}{}", sign, parts.join(separator))
+}
@@ -20,12 +20,58 @@
 //! assert_eq!(formatted, "1m");
 //!
 //! // With long format - use format() function
-//! let formatted = format(60000, Some(Options { long: true }));
+//! let formatted = format(60000, Some(Options::new().long(true)));
 //! assert_eq!(formatted, "1 minute");
 //! ```
 
 use regex::Regex;
-use std::sync::OnceLock;
+use std::borrow::Cow;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::{Arc, OnceLock};
+
+mod approx;
+mod breakdown;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "clap")]
+mod clap;
+mod decimal;
+mod duration;
+mod env;
+mod exact;
+mod human;
+#[cfg(feature = "serde")]
+mod iso;
+#[cfg(feature = "jiff")]
+mod jiff;
+mod number;
+mod scan;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod template;
+#[cfg(feature = "time")]
+mod time;
+mod unicode;
+mod unit;
+
+#[cfg(feature = "chrono")]
+pub use self::chrono::{add_calendar, format_time_delta, parse_time_delta};
+#[cfg(feature = "clap")]
+pub use self::clap::DurationParser;
+#[cfg(feature = "jiff")]
+pub use self::jiff::{format_span, parse_span};
+#[cfg(feature = "time")]
+pub use self::time::{add_duration, format_time_duration, parse_time_duration, sub_duration};
+pub use approx::{ApproxOptions, format_approx};
+pub use breakdown::Breakdown;
+pub use duration::Millis;
+pub use env::{EnvError, EnvOptions, env_duration, env_duration_with, env_value_duration};
+pub use exact::format_exact;
+pub use human::HumanDuration;
+pub use scan::{DurationMatch, Durations, find_durations, find_durations_with};
+pub use template::format_template;
+pub use unit::Unit;
 
 // Time unit constants in milliseconds
 const S: f64 = 1000.0;
@@ -35,12 +81,307 @@ const D: f64 = H * 24.0;
 const W: f64 = D * 7.0;
 const Y: f64 = D * 365.25;
 const MO: f64 = Y / 12.0;
+const FN: f64 = W * 2.0;
+const DEC: f64 = Y * 10.0;
+const CENT: f64 = Y * 100.0;
+const MILL: f64 = Y * 1000.0;
+
+// Units the formatter may choose from, longest first
+const FORMAT_UNITS: &[Unit] = &[
+    Unit::Millennia,
+    Unit::Centuries,
+    Unit::Decades,
+    Unit::Years,
+    Unit::Months,
+    Unit::Fortnights,
+    Unit::Weeks,
+    Unit::Days,
+    Unit::Hours,
+    Unit::Minutes,
+    Unit::Seconds,
+    Unit::Milliseconds,
+    Unit::Microseconds,
+];
+
+// Unsigned numbers: integers, decimals and vulgar fractions ("1¼", "½").
+// `\d` matches any Unicode decimal digit.
+const NUMBER: &str = r"(?:\d+[½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒↉]|\d*\.?\d+|[½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒↉])";
 
-/// Options for formatting milliseconds
-#[derive(Debug, Clone, Copy, Default)]
+// Unit names accepted after a number
+const UNITS: &str = "microseconds?|µs|μs|usecs?|us|milliseconds?|msecs?|ms|millennia|millennium|centuries|century|decades?|fortnights?|seconds?|secs?|s|minutes?|mins?|m|hours?|hrs?|h|days?|d|weeks?|wks?|w|months?|mths?|mo|years?|yrs?|y";
+
+// Chinese and Japanese unit names, which are not followed by a word boundary.
+// Date markers such as 年, 月, 日 and 時 are left out, so "2024年3月5日" is a
+// date rather than three durations.
+const CJK_UNITS: &str = "毫秒|秒|分钟|分鐘|分|小时|小時|時間|天|星期|周|週|个月|個月|ヶ月|か月";
+
+/// Options for formatting milliseconds.
+///
+/// New options may be added in minor releases, so build them with
+/// [`Options::new()`] and the setters rather than a struct expression.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{format, Options, Precision, Style};
+///
+/// let options = Options::new()
+///     .style(Style::Short)
+///     .precision(Precision::Decimals(1));
+/// assert_eq!(format(5400000, Some(options)), "1.5 hrs");
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
 pub struct Options {
-    /// Set to `true` to use verbose formatting. Defaults to `false`.
+    /// Set to `true` to use verbose formatting. Same as `style: Style::Long`,
+    /// and takes precedence over `style`. Defaults to `false`.
     pub long: bool,
+    /// Width of the unit names: "1h", "1 hr" or "1 hour". Defaults to
+    /// [`Style::Narrow`].
+    pub style: Style,
+    /// Set to `true` to write months as `M` in narrow style, matching
+    /// case-sensitive parsing. Defaults to `false`.
+    pub case_sensitive: bool,
+    /// How the ambiguous single-letter unit `m` is treated. Defaults to
+    /// [`MPolicy::Minutes`].
+    pub m_policy: MPolicy,
+    /// Set to `true` to also format with decades, centuries and millennia
+    /// (e.g. "3 centuries" instead of "300 years"). Defaults to `false`.
+    pub extended_units: bool,
+    /// How many digits to show, e.g. `Some(Precision::Decimals(1))` formats
+    /// 5400000 as "1.5h". Defaults to `None`, which rounds to whole units.
+    pub precision: Option<Precision>,
+    /// Set to `true` to drop trailing zeros after the decimal point, so
+    /// "2.00h" becomes "2h". Defaults to `false`.
+    pub trim_zeros: bool,
+    /// How the value is rounded to the digits shown. Defaults to
+    /// [`RoundingMode::HalfUp`].
+    pub rounding: RoundingMode,
+    /// The largest unit to format with, e.g. `Some(Unit::Hours)` formats two
+    /// days as "48h". Defaults to `None`, meaning years (or millennia with
+    /// `extended_units`).
+    pub largest_unit: Option<Unit>,
+    /// The smallest unit to format with. Shorter values are rounded to this
+    /// unit, e.g. "0s" or "1s". Defaults to `None`, meaning milliseconds.
+    pub smallest_unit: Option<Unit>,
+    /// Always format with this unit, e.g. `Some(Unit::Seconds)` formats
+    /// 5400000 as "5400s". Takes precedence over `largest_unit` and
+    /// `smallest_unit`. Defaults to `None`.
+    pub unit: Option<Unit>,
+    /// Set to `true` for the word styles to match vercel/ms: milliseconds
+    /// are written as "ms" and units are plural from 1.5 units up, so
+    /// 1000 ms with `smallest_unit: Some(Unit::Minutes)` is "0 minute".
+    /// Defaults to `false`, which pluralizes the value shown.
+    pub legacy_plural: bool,
+}
+
+/// Number of digits shown for the value of a formatted unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    /// A fixed number of digits after the decimal point, e.g. `Decimals(2)`
+    /// formats 5400000 as "1.50h".
+    Decimals(u8),
+    /// A number of significant digits, e.g. `Significant(2)` formats 5400000
+    /// as "1.5h" and 12345678 as "3.4h".
+    Significant(u8),
+}
+
+impl Options {
+    /// Create the default options.
+    pub const fn new() -> Options {
+        Options {
+            long: false,
+            style: Style::Narrow,
+            case_sensitive: false,
+            m_policy: MPolicy::Minutes,
+            extended_units: false,
+            precision: None,
+            trim_zeros: false,
+            rounding: RoundingMode::HalfUp,
+            largest_unit: None,
+            smallest_unit: None,
+            unit: None,
+            legacy_plural: false,
+        }
+    }
+
+    /// Set [`long`](Options::long).
+    pub const fn long(mut self, long: bool) -> Options {
+        self.long = long;
+        self
+    }
+
+    /// Set [`style`](Options::style).
+    pub const fn style(mut self, style: Style) -> Options {
+        self.style = style;
+        self
+    }
+
+    /// Set [`case_sensitive`](Options::case_sensitive).
+    pub const fn case_sensitive(mut self, case_sensitive: bool) -> Options {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set [`m_policy`](Options::m_policy).
+    pub const fn m_policy(mut self, m_policy: MPolicy) -> Options {
+        self.m_policy = m_policy;
+        self
+    }
+
+    /// Set [`extended_units`](Options::extended_units).
+    pub const fn extended_units(mut self, extended_units: bool) -> Options {
+        self.extended_units = extended_units;
+        self
+    }
+
+    /// Set [`precision`](Options::precision).
+    pub const fn precision(mut self, precision: Precision) -> Options {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Set [`trim_zeros`](Options::trim_zeros).
+    pub const fn trim_zeros(mut self, trim_zeros: bool) -> Options {
+        self.trim_zeros = trim_zeros;
+        self
+    }
+
+    /// Set [`rounding`](Options::rounding).
+    pub const fn rounding(mut self, rounding: RoundingMode) -> Options {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set [`largest_unit`](Options::largest_unit).
+    pub const fn largest_unit(mut self, unit: Unit) -> Options {
+        self.largest_unit = Some(unit);
+        self
+    }
+
+    /// Set [`smallest_unit`](Options::smallest_unit).
+    pub const fn smallest_unit(mut self, unit: Unit) -> Options {
+        self.smallest_unit = Some(unit);
+        self
+    }
+
+    /// Set [`unit`](Options::unit).
+    pub const fn unit(mut self, unit: Unit) -> Options {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Set [`legacy_plural`](Options::legacy_plural).
+    pub const fn legacy_plural(mut self, legacy_plural: bool) -> Options {
+        self.legacy_plural = legacy_plural;
+        self
+    }
+
+    /// The style in effect, taking the `long` flag into account
+    fn effective_style(&self) -> Style {
+        if self.long { Style::Long } else { self.style }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options::new()
+    }
+}
+
+/// Width of the unit names written by `format()`, following the narrow, short
+/// and long unit styles of `Intl.DurationFormat`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Style {
+    /// Symbols without a space, e.g. "1h" and "2h". Fortnights, decades,
+    /// centuries and millennia have no symbol and are written in full.
+    #[default]
+    Narrow,
+    /// Abbreviations, e.g. "1 hr" and "2 hrs".
+    Short,
+    /// Full words, e.g. "1 hour" and "2 hours".
+    Long,
+}
+
+/// Options for parsing time strings.
+///
+/// New options may be added in minor releases, so build them with
+/// [`ParseOptions::new()`] and the setters rather than a struct expression.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// Set to `true` to match unit names case-sensitively: units must be
+    /// lowercase, except `M` which means months. Defaults to `false`.
+    pub case_sensitive: bool,
+    /// How the ambiguous single-letter unit `m` is treated. Defaults to
+    /// [`MPolicy::Minutes`].
+    pub m_policy: MPolicy,
+    /// How the result is rounded to whole milliseconds. Defaults to
+    /// [`RoundingMode::HalfUp`].
+    pub rounding: RoundingMode,
+}
+
+impl ParseOptions {
+    /// Create the default options.
+    pub const fn new() -> ParseOptions {
+        ParseOptions {
+            case_sensitive: false,
+            m_policy: MPolicy::Minutes,
+            rounding: RoundingMode::HalfUp,
+        }
+    }
+
+    /// Set [`case_sensitive`](ParseOptions::case_sensitive).
+    pub const fn case_sensitive(mut self, case_sensitive: bool) -> ParseOptions {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set [`m_policy`](ParseOptions::m_policy).
+    pub const fn m_policy(mut self, m_policy: MPolicy) -> ParseOptions {
+        self.m_policy = m_policy;
+        self
+    }
+
+    /// Set [`rounding`](ParseOptions::rounding).
+    pub const fn rounding(mut self, rounding: RoundingMode) -> ParseOptions {
+        self.rounding = rounding;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions::new()
+    }
+}
+
+/// How values are rounded: to whole milliseconds when parsing, and to the
+/// digits shown when formatting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round towards negative infinity, e.g. for countdowns.
+    Floor,
+    /// Round towards positive infinity, e.g. for budgets.
+    Ceil,
+    /// Round towards zero.
+    Truncate,
+    /// Round to the nearest value, ties to the even neighbour.
+    HalfEven,
+    /// Round to the nearest value, ties away from zero.
+    #[default]
+    HalfUp,
+}
+
+/// Policy for the single-letter unit `m`, which may mean minutes or months.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MPolicy {
+    /// `m` means minutes. The narrow style writes minutes as `m`.
+    #[default]
+    Minutes,
+    /// `m` and `M` are rejected when parsing; the narrow style writes minutes as
+    /// `min` and months as `mo`.
+    Reject,
 }
 
 /// Trait for types that can be converted to/from milliseconds
@@ -67,15 +408,30 @@ impl ToMillis for String {
     }
 }
 
-/// Implementation for i64 - converts milliseconds to formatted string
-impl ToMillis for i64 {
+/// Implementation for numbers - converts milliseconds to formatted string
+impl<T: number::Number> ToMillis for T {
     type Output = Result<String, String>;
 
     fn to_millis(self) -> Result<String, String> {
-        Ok(format(self, None))
+        Ok(format(self.to_i64()?, None))
     }
 }
 
+/// Implementations for string-like types - convert string to milliseconds
+macro_rules! impl_to_millis_for_str {
+    ($($t:ty),*) => {$(
+        impl ToMillis for $t {
+            type Output = Result<i64, String>;
+
+            fn to_millis(self) -> Result<i64, String> {
+                parse(&self)
+            }
+        }
+    )*};
+}
+
+impl_to_millis_for_str!(&String, Box<str>, Cow<'_, str>, Rc<str>, Arc<str>);
+
 /// Parse or format the given value.
 ///
 /// This is a unified interface that can handle both strings (parsing to milliseconds)
@@ -88,7 +444,12 @@ impl ToMillis for i64 {
 /// # Returns
 ///
 /// * `Result<i64, String>` if input was a string (parsed to milliseconds)
-/// * `Result<String, String>` if input was a number (formatted to time string)
+/// * `Result<String, String>` if input was a number or a [`Millis`] (formatted
+///   to time string)
+///
+/// The return type follows from the input alone, so parsing a string always
+/// gives an `i64`, as it did before `Millis` existed. To get a `Millis`, parse
+/// it directly with `"2h".parse::<Millis>()`.
 ///
 /// # Errors
 ///
@@ -109,6 +470,14 @@ impl ToMillis for i64 {
 /// // Format milliseconds to string
 /// let formatted = ms(7200000).unwrap();
 /// assert_eq!(formatted, "2h");
+///
+/// // Untyped literals are i32, so larger values need a suffix
+/// assert_eq!(ms(31557600000_i64).unwrap(), "1y");
+///
+/// // A `Millis` is formatted like a number
+/// use millis::Millis;
+/// let timeout: Millis = "2h".parse().unwrap();
+/// assert_eq!(ms(timeout).unwrap(), "2h");
 /// ```
 pub fn ms<T: ToMillis>(value: T) -> T::Output {
     value.to_millis()
@@ -122,11 +491,22 @@ pub fn ms<T: ToMillis>(value: T) -> T::Output {
 ///
 /// # Returns
 ///
-/// The parsed value in milliseconds as `i64`, or an error for invalid inputs.
+/// The parsed value in milliseconds as `i64`, or an error for invalid inputs
+/// and for durations beyond the `i64` range (see `parse_i128()`).
 ///
 /// # Note
 ///
-/// Values are rounded to the nearest integer millisecond.
+/// Values are rounded to the nearest integer millisecond, with ties rounded
+/// away from zero. Use `parse_with()` to choose another `RoundingMode`.
+///
+/// The number is read as an exact decimal rather than an `f64`, so every
+/// integer is exact and fractional values are rounded once. Digits after the
+/// 24th decimal place only break ties.
+///
+/// Input pasted from documents is normalized first: any Unicode decimal digits
+/// (e.g. "５"), vulgar fractions ("½h", "1¼ hours"), the micro sign or Greek mu
+/// ("10 µs"), the minus sign "−" and non-breaking spaces are all accepted,
+/// as are Chinese and Japanese unit names ("５分").
 ///
 /// # Examples
 ///
@@ -138,8 +518,121 @@ pub fn ms<T: ToMillis>(value: T) -> T::Output {
 /// assert_eq!(parse("30 minutes").unwrap(), 1800000);
 /// assert_eq!(parse("-1h").unwrap(), -3600000);
 /// assert!(parse("invalid").is_err());
+///
+/// assert_eq!(parse("½h").unwrap(), 1800000);
+/// assert_eq!(parse("５分").unwrap(), 300000);
+/// assert_eq!(parse("1500 µs").unwrap(), 2);
 /// ```
 pub fn parse(s: &str) -> Result<i64, String> {
+    parse_with(s, None)
+}
+
+/// Parse the given string with options and return milliseconds.
+///
+/// # Arguments
+///
+/// * `s` - A string to parse to milliseconds (e.g., "2h", "1d", "10 seconds")
+/// * `options` - Options for the conversion (`case_sensitive` units, `m_policy`,
+///   `rounding` to whole milliseconds)
+///
+/// # Examples
+///
+/// ```
+/// use millis::{parse_with, MPolicy, ParseOptions, RoundingMode};
+///
+/// let java = ParseOptions::new().case_sensitive(true);
+/// assert_eq!(parse_with("3M", Some(java)).unwrap(), 7889400000);
+/// assert_eq!(parse_with("3m", Some(java)).unwrap(), 180000);
+/// assert!(parse_with("3H", Some(java)).is_err());
+///
+/// let strict = ParseOptions::new().m_policy(MPolicy::Reject);
+/// assert!(parse_with("3m", Some(strict)).is_err());
+/// assert_eq!(parse_with("3min", Some(strict)).unwrap(), 180000);
+///
+/// let floor = ParseOptions::new().rounding(RoundingMode::Floor);
+/// assert_eq!(parse_with("1.9ms", Some(floor)).unwrap(), 1);
+/// ```
+pub fn parse_with(s: &str, options: Option<ParseOptions>) -> Result<i64, String> {
+    let opts = options.unwrap_or_default();
+    parse_parts(s, &opts)?
+        .round(1, opts.rounding)
+        .and_then(|total| i64::try_from(total).ok())
+        .ok_or_else(|| format!("Duration out of range. value={:?}", s))
+}
+
+/// Parse the given string and return milliseconds, for durations beyond the
+/// `i64` range.
+///
+/// Accepts the same strings as `parse()`, and is exact across the full `i128`
+/// range.
+///
+/// # Errors
+///
+/// Returns an error for invalid input, or if the result does not fit in an
+/// `i128`.
+///
+/// # Examples
+///
+/// ```
+/// use millis::parse_i128;
+///
+/// assert_eq!(parse_i128("2h").unwrap(), 7200000);
+/// assert_eq!(parse_i128("300000000y").unwrap(), 9467280000000000000);
+/// assert_eq!(parse_i128("-9223372036854775809").unwrap(), -9223372036854775809);
+/// assert!(parse_i128("99999999999999999999999999999y").is_err());
+/// ```
+pub fn parse_i128(s: &str) -> Result<i128, String> {
+    let opts = ParseOptions::default();
+    parse_parts(s, &opts)?
+        .round(1, opts.rounding)
+        .ok_or_else(|| format!("Duration out of range. value={:?}", s))
+}
+
+/// Parse `s` into nanoseconds, rounded to the nearest with ties away from zero
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn parse_nanos(s: &str) -> Result<i128, String> {
+    let opts = ParseOptions::default();
+    parse_parts(s, &opts)?
+        .round(1_000_000, opts.rounding)
+        .ok_or_else(|| format!("Duration out of range. value={:?}", s))
+}
+
+/// Parse `s` into milliseconds without rounding
+fn parse_unrounded(s: &str, opts: &ParseOptions) -> Result<f64, String> {
+    Ok(parse_parts(s, opts)?.millis())
+}
+
+/// A time string split into its sign and `(number, unit)` parts
+struct Parsed<'t> {
+    negative: bool,
+    parts: Vec<(&'t str, Unit)>,
+}
+
+impl Parsed<'_> {
+    /// Total in milliseconds
+    fn millis(&self) -> f64 {
+        let total: f64 = self
+            .parts
+            .iter()
+            .map(|&(value, unit)| unicode::parse_number(value).unwrap_or(0.0) * unit.millis())
+            .sum();
+        if self.negative { -total } else { total }
+    }
+
+    /// Total rounded to multiples of `1 / per_ms` milliseconds, computed
+    /// exactly. Returns `None` if it does not fit in an `i128`.
+    fn round(&self, per_ms: u128, mode: RoundingMode) -> Option<i128> {
+        let mut sum = decimal::ExactSum::default();
+        for &(value, unit) in &self.parts {
+            sum.add(value, unit)?;
+        }
+        sum.round(per_ms, self.negative, mode)
+    }
+}
+
+/// Split `s` into its sign and parts, either a single number with an optional
+/// unit ("2h", "100") or several numbers with units ("1h30m", "1d 12h")
+fn parse_parts<'t>(s: &'t str, opts: &ParseOptions) -> Result<Parsed<'t>, String> {
     if s.is_empty() || s.len() > 100 {
         return Err(format!(
             "Value provided to parse() must be a string with length between 1 and 100. value={:?}",
@@ -147,41 +640,74 @@ pub fn parse(s: &str) -> Result<i64, String> {
         ));
     }
 
-    static RE: OnceLock<Regex> = OnceLock::new();
-    let re = RE.get_or_init(|| {
-        Regex::new(r"(?i)^(?P<value>-?\d*\.?\d+)\s*(?P<unit>milliseconds?|msecs?|ms|seconds?|secs?|s|minutes?|mins?|m|hours?|hrs?|h|days?|d|weeks?|w|months?|mo|years?|yrs?|y)?$")
-            .unwrap()
+    static SINGLE: OnceLock<Regex> = OnceLock::new();
+    static COMPOUND: OnceLock<Regex> = OnceLock::new();
+    static FIRST: OnceLock<Regex> = OnceLock::new();
+    let part = || format!(r"{NUMBER}\s*(?:{UNITS}|{CJK_UNITS})");
+    let single = SINGLE.get_or_init(|| {
+        Regex::new(&format!(
+            r"(?i)^(?P<sign>[-−]?)(?P<value>{NUMBER})\s*(?P<unit>{UNITS}|{CJK_UNITS})?$"
+        ))
+        .unwrap()
+    });
+    let compound = COMPOUND.get_or_init(|| {
+        let part = part();
+        Regex::new(&format!(
+            r"(?i)^(?P<sign>[-−]?)(?P<parts>{part}(?:\s*{part})+)$"
+        ))
+        .unwrap()
+    });
+    // The first part of the rest, such that the rest still parses: "1mo30d"
+    // splits into "1mo" and "30d", not "1m" and "o30d"
+    let first = FIRST.get_or_init(|| {
+        let part = part();
+        Regex::new(&format!(
+            r"(?i)^(?P<value>{NUMBER})\s*(?P<unit>{UNITS}|{CJK_UNITS})\s*(?P<rest>(?:{part}\s*)*)$"
+        ))
+        .unwrap()
     });
 
-    let caps = match re.captures(s) {
-        Some(c) => c,
-        None => return Err(format!("Invalid time string format. value={:?}", s)),
-    };
-
-    let value: f64 = match caps["value"].parse() {
-        Ok(v) => v,
-        Err(_) => return Err(format!("Invalid numeric value. value={:?}", s)),
-    };
+    if let Some(caps) = single.captures(s) {
+        let value = caps.name("value").unwrap().as_str();
+        if unicode::parse_number(value).is_none() {
+            return Err(format!("Invalid numeric value. value={:?}", s));
+        }
+        let unit = caps.name("unit").map_or("ms", |m| m.as_str());
+        return Ok(Parsed {
+            negative: !caps["sign"].is_empty(),
+            parts: vec![(value, lookup_unit(unit, s, opts)?)],
+        });
+    }
 
-    let unit = caps
-        .name("unit")
-        .map(|m| m.as_str().to_lowercase())
-        .unwrap_or_else(|| "ms".to_string());
-
-    let multiplier = match unit.as_str() {
-        "years" | "year" | "yrs" | "yr" | "y" => Y,
-        "months" | "month" | "mo" => MO,
-        "weeks" | "week" | "w" => W,
-        "days" | "day" | "d" => D,
-        "hours" | "hour" | "hrs" | "hr" | "h" => H,
-        "minutes" | "minute" | "mins" | "min" | "m" => M,
-        "seconds" | "second" | "secs" | "sec" | "s" => S,
-        "milliseconds" | "millisecond" | "msecs" | "msec" | "ms" => 1.0,
-        _ => return Err(format!("Unknown unit {:?}. value={:?}", unit, s)),
+    let Some(caps) = compound.captures(s) else {
+        return Err(format!("Invalid time string format. value={:?}", s));
     };
+    let mut parts = Vec::new();
+    let mut rest = caps.name("parts").unwrap().as_str();
+    while let Some(caps) = first.captures(rest) {
+        let value = caps.name("value").unwrap().as_str();
+        if unicode::parse_number(value).is_none() {
+            return Err(format!("Invalid numeric value. value={:?}", s));
+        }
+        parts.push((value, lookup_unit(&caps["unit"], s, opts)?));
+        rest = caps.name("rest").unwrap().as_str();
+    }
+    Ok(Parsed {
+        negative: !caps["sign"].is_empty(),
+        parts,
+    })
+}
 
-    let result = value * multiplier;
-    Ok(result.round() as i64)
+/// Look up the unit named `unit` in the time string `s`
+fn lookup_unit(unit: &str, s: &str, opts: &ParseOptions) -> Result<Unit, String> {
+    match Unit::from_name(unit, opts) {
+        Some(u) => Ok(u),
+        None if unit.eq_ignore_ascii_case("m") => Err(format!(
+            "Ambiguous unit {:?}, use \"min\" or \"mo\". value={:?}",
+            unit, s
+        )),
+        None => Err(format!("Unknown unit {:?}. value={:?}", unit, s)),
+    }
 }
 
 /// Parse the given string and return milliseconds (strict version).
@@ -199,12 +725,53 @@ pub fn parse_strict(s: &str) -> Result<i64, String> {
     parse(s)
 }
 
+/// Parse the given string and return its value in `unit`.
+///
+/// The value is not rounded to whole milliseconds first, so "1500 µs" is
+/// exactly 1.5 milliseconds.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{parse_as, Unit};
+///
+/// assert_eq!(parse_as("90m", Unit::Hours).unwrap(), 1.5);
+/// assert_eq!(parse_as("2h", Unit::Seconds).unwrap(), 7200.0);
+/// assert_eq!(parse_as("1500 µs", Unit::Milliseconds).unwrap(), 1.5);
+/// assert!(parse_as("invalid", Unit::Seconds).is_err());
+/// ```
+pub fn parse_as(s: &str, unit: Unit) -> Result<f64, String> {
+    let result = parse_unrounded(s, &ParseOptions::default())?;
+    Ok(result / unit.millis())
+}
+
+/// Convert milliseconds to a (possibly fractional) number of `unit`s.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{to_unit, Unit};
+///
+/// assert_eq!(to_unit(5400000, Unit::Hours), 1.5);
+/// assert_eq!(to_unit(5400000, Unit::Seconds), 5400.0);
+/// assert_eq!(to_unit(-1, Unit::Microseconds), -1000.0);
+/// ```
+pub fn to_unit(ms: i64, unit: Unit) -> f64 {
+    ms as f64 / unit.millis()
+}
+
 /// Format the given milliseconds as a string.
 ///
 /// # Arguments
 ///
 /// * `ms` - milliseconds to format
-/// * `options` - Options for the conversion (use `long` format if specified)
+/// * `options` - Options for the conversion (`style` or `long` choose the unit
+///   names,
+///   `case_sensitive` and `m_policy` choose the short unit names,
+///   `extended_units` allows decades, centuries and millennia, `precision`,
+///   `trim_zeros` and `rounding` control the digits shown, `largest_unit` and
+///   `smallest_unit` bound the unit chosen, `unit` forces one, `legacy_plural`
+///   matches the vercel/ms word output)
 ///
 /// # Returns
 ///
@@ -213,73 +780,194 @@ pub fn parse_strict(s: &str) -> Result<i64, String> {
 /// # Examples
 ///
 /// ```
-/// use millis::{format, Options};
+/// use millis::{format, Options, Precision, Style, Unit};
 ///
 /// assert_eq!(format(60000, None), "1m");
-/// assert_eq!(format(60000, Some(Options { long: true })), "1 minute");
+/// assert_eq!(format(60000, Some(Options::new().long(true))), "1 minute");
 /// assert_eq!(format(3600000, None), "1h");
 /// assert_eq!(format(-3600000, None), "-1h");
+///
+/// let short = Options::new().style(Style::Short);
+/// assert_eq!(format(7200000, Some(short)), "2 hrs");
+///
+/// let java = Options::new().case_sensitive(true);
+/// assert_eq!(format(7889400000, Some(java)), "3M");
+///
+/// let extended = Options::new().extended_units(true).long(true);
+/// assert_eq!(format(9467280000000, Some(extended)), "3 centuries");
+///
+/// let precise = Options::new().precision(Precision::Decimals(1));
+/// assert_eq!(format(5400000, Some(precise)), "1.5h");
+///
+/// let hours = Options::new().largest_unit(Unit::Hours);
+/// assert_eq!(format(129600000, Some(hours)), "36h");
+///
+/// let seconds = Options::new().unit(Unit::Seconds);
+/// assert_eq!(format(5400000, Some(seconds)), "5400s");
 /// ```
 pub fn format(ms: i64, options: Option<Options>) -> String {
+    let mut out = String::new();
+    // Writing to a `String` cannot fail
+    let _ = format_into(&mut out, ms, options);
+    out
+}
+
+/// Format the given milliseconds into a writer, such as a `String` or a
+/// `fmt::Formatter`, without allocating.
+///
+/// This is `format()` for callers that already have a buffer.
+///
+/// # Errors
+///
+/// Returns an error only if the writer does.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{format_into, Options};
+/// use std::fmt::Write;
+///
+/// let mut line = String::from("took ");
+/// format_into(&mut line, 5400000, None).unwrap();
+/// write!(line, ", limit ").unwrap();
+/// format_into(&mut line, 7200000, Some(Options::new().long(true))).unwrap();
+/// assert_eq!(line, "took 2h, limit 2 hours");
+/// ```
+pub fn format_into(out: &mut impl fmt::Write, ms: i64, options: Option<Options>) -> fmt::Result {
     let opts = options.unwrap_or_default();
-    if opts.long {
-        fmt_long(ms)
-    } else {
-        fmt_short(ms)
-    }
-}
-
-/// Short format for milliseconds
-fn fmt_short(ms: i64) -> String {
-    let ms_abs = ms.abs();
-    let ms_f64 = ms as f64;
-
-    if ms_abs >= Y as i64 {
-        format!("{}y", (ms_f64 / Y).round() as i64)
-    } else if ms_abs >= MO as i64 {
-        format!("{}mo", (ms_f64 / MO).round() as i64)
-    } else if ms_abs >= W as i64 {
-        format!("{}w", (ms_f64 / W).round() as i64)
-    } else if ms_abs >= D as i64 {
-        format!("{}d", (ms_f64 / D).round() as i64)
-    } else if ms_abs >= H as i64 {
-        format!("{}h", (ms_f64 / H).round() as i64)
-    } else if ms_abs >= M as i64 {
-        format!("{}m", (ms_f64 / M).round() as i64)
-    } else if ms_abs >= S as i64 {
-        format!("{}s", (ms_f64 / S).round() as i64)
+    match opts.effective_style() {
+        Style::Narrow => fmt_narrow(out, i128::from(ms), &opts),
+        style => fmt_words(out, i128::from(ms), style, &opts),
+    }
+}
+
+/// Format the given milliseconds as a string, for durations beyond the `i64`
+/// range.
+///
+/// Takes the same options as `format()`.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{format_i128, Options, Unit};
+///
+/// assert_eq!(format_i128(i128::from(i64::MIN), None), "-292271023y");
+/// assert_eq!(format_i128(-i128::MAX, None), "-5391448762278159040348039893y");
+///
+/// // The age of the universe
+/// let extended = Options::new().extended_units(true).long(true);
+/// assert_eq!(format_i128(435494880000000000000, Some(extended)), "13800000 millennia");
+/// ```
+pub fn format_i128(ms: i128, options: Option<Options>) -> String {
+    let opts = options.unwrap_or_default();
+    let mut out = String::new();
+    // Writing to a `String` cannot fail
+    let _ = match opts.effective_style() {
+        Style::Narrow => fmt_narrow(&mut out, ms, &opts),
+        style => fmt_words(&mut out, ms, style, &opts),
+    };
+    out
+}
+
+/// Largest unit to format `ms_abs` with, within the unit bounds
+fn format_unit(ms_abs: u128, opts: &Options) -> Unit {
+    if let Some(unit) = opts.unit {
+        return unit;
+    }
+
+    let default_largest = if opts.extended_units {
+        Unit::Millennia
     } else {
-        format!("{}ms", ms)
-    }
-}
-
-/// Long format for milliseconds
-fn fmt_long(ms: i64) -> String {
-    let ms_abs = ms.abs();
-    let ms_f64 = ms as f64;
-
-    if ms_abs >= Y as i64 {
-        plural(ms_f64, ms_abs as f64, Y, "year")
-    } else if ms_abs >= MO as i64 {
-        plural(ms_f64, ms_abs as f64, MO, "month")
-    } else if ms_abs >= W as i64 {
-        plural(ms_f64, ms_abs as f64, W, "week")
-    } else if ms_abs >= D as i64 {
-        plural(ms_f64, ms_abs as f64, D, "day")
-    } else if ms_abs >= H as i64 {
-        plural(ms_f64, ms_abs as f64, H, "hour")
-    } else if ms_abs >= M as i64 {
-        plural(ms_f64, ms_abs as f64, M, "minute")
-    } else if ms_abs >= S as i64 {
-        plural(ms_f64, ms_abs as f64, S, "second")
+        Unit::Years
+    };
+    let largest = opts.largest_unit.unwrap_or(default_largest);
+    let smallest = opts.smallest_unit.unwrap_or(Unit::Milliseconds);
+    let (largest, smallest) = (largest.max(smallest), largest.min(smallest));
+
+    let units = FORMAT_UNITS
+        .iter()
+        .copied()
+        .filter(|&u| u <= largest && u >= smallest)
+        // Fortnights are only used when asked for explicitly
+        .filter(|&u| u != Unit::Fortnights || u == largest || u == smallest);
+
+    let mut unit = smallest;
+    for u in units {
+        unit = u;
+        let (num, den) = u.ratio();
+        if ms_abs.saturating_mul(den) >= num {
+            break;
+        }
+    }
+    unit
+}
+
+/// Number of `unit`s in `ms`, rendered with the precision options. Returns
+/// the sign shown and the magnitude.
+fn fmt_value(ms: i128, unit: Unit, opts: &Options) -> (&'static str, decimal::Decimal) {
+    let (num, den) = unit.ratio();
+    let how = decimal::Rendering {
+        precision: opts.precision,
+        trim: opts.trim_zeros,
+        rounding: opts.rounding,
+        negative: ms < 0,
+    };
+    let value = match ms.unsigned_abs().checked_mul(den) {
+        Some(scaled) => decimal::render(scaled, num, how),
+        // Only microseconds scale up, and overflow beyond about 3.4e35 ms
+        None => decimal::render_thousandfold(ms.unsigned_abs(), how),
+    };
+    // Values that round to zero are shown without a sign
+    if ms < 0 && value.as_str().bytes().any(|b| matches!(b, b'1'..=b'9')) {
+        ("-", value)
     } else {
-        format!("{} ms", ms)
+        ("", value)
+    }
+}
+
+/// Symbol of `unit` in the narrow style, following the `m` policy and case
+/// sensitivity so that the output parses back with the same options
+pub(crate) fn narrow_symbol(unit: Unit, opts: &Options) -> Option<&'static str> {
+    match (unit, opts.m_policy, opts.case_sensitive) {
+        (Unit::Minutes, MPolicy::Reject, _) => Some("min"),
+        (Unit::Months, MPolicy::Minutes, true) => Some("M"),
+        _ => unit.symbol(),
     }
 }
 
-/// Pluralization helper
-fn plural(ms: f64, ms_abs: f64, n: f64, name: &str) -> String {
-    let is_plural = ms_abs >= n * 1.5;
-    let value = (ms / n).round() as i64;
-    format!("{} {}{}", value, name, if is_plural { "s" } else { "" })
+/// Narrow format for milliseconds
+fn fmt_narrow(out: &mut impl fmt::Write, ms: i128, opts: &Options) -> fmt::Result {
+    let unit = format_unit(ms.unsigned_abs(), opts);
+    let Some(symbol) = narrow_symbol(unit, opts) else {
+        return fmt_words(out, ms, Style::Long, opts);
+    };
+    let (sign, value) = fmt_value(ms, unit, opts);
+    write!(out, "{}{}{}", sign, value, symbol)
+}
+
+/// Short or long format for milliseconds
+fn fmt_words(out: &mut impl fmt::Write, ms: i128, style: Style, opts: &Options) -> fmt::Result {
+    let unit = format_unit(ms.unsigned_abs(), opts);
+    let (sign, value) = fmt_value(ms, unit, opts);
+    if opts.legacy_plural && unit == Unit::Milliseconds {
+        return write!(out, "{}{} ms", sign, value);
+    }
+
+    // Only exactly "1" is singular: "0 seconds", "1.0 hours", "1.5 hours"
+    let is_plural = match opts.precision {
+        None if opts.legacy_plural => ms.unsigned_abs() as f64 >= unit.millis() * 1.5,
+        _ => value.as_str() != "1",
+    };
+    let (singular, plural) = if style == Style::Short {
+        unit.abbreviations()
+    } else {
+        unit.names()
+    };
+    write!(
+        out,
+        "{}{} {}",
+        sign,
+        value,
+        if is_plural { plural } else { singular }
+    )
 }
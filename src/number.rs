@@ -0,0 +1,61 @@
+//! Numbers accepted by `ms()` for formatting.
+//!
+//! Every number type goes through one blanket `ToMillis` impl, so an untyped
+//! literal such as `ms(500)` still infers its output type and falls back to
+//! `i32`. Unlike 1.x, where only `i64` was accepted, an untyped literal above
+//! `i32::MAX` no longer compiles and needs a suffix, as in `ms(31557600000_i64)`.
+
+/// A primitive number of milliseconds
+pub trait Number: Copy + std::fmt::Debug {
+    /// The value as whole milliseconds, or an error if it does not fit in i64
+    fn to_i64(self) -> Result<i64, String>;
+}
+
+fn out_of_range(value: impl std::fmt::Debug) -> String {
+    format!("Value out of range for milliseconds. value={:?}", value)
+}
+
+// Integers that always fit in i64
+macro_rules! impl_number_for_int {
+    ($($t:ty),*) => {$(
+        impl Number for $t {
+            fn to_i64(self) -> Result<i64, String> {
+                Ok(i64::from(self))
+            }
+        }
+    )*};
+}
+
+impl_number_for_int!(i8, i16, i32, i64, u8, u16, u32);
+
+// Wider integers, checked against the i64 range
+macro_rules! impl_number_for_wide_int {
+    ($($t:ty),*) => {$(
+        impl Number for $t {
+            fn to_i64(self) -> Result<i64, String> {
+                i64::try_from(self).map_err(|_| out_of_range(self))
+            }
+        }
+    )*};
+}
+
+impl_number_for_wide_int!(i128, isize, u64, u128, usize);
+
+// Floats, rounded to the nearest millisecond
+macro_rules! impl_number_for_float {
+    ($($t:ty),*) => {$(
+        impl Number for $t {
+            fn to_i64(self) -> Result<i64, String> {
+                // i64::MIN is -2^63, and every float below 2^63 fits
+                let ms = f64::from(self).round();
+                if ms >= -(2f64.powi(63)) && ms < 2f64.powi(63) {
+                    Ok(ms as i64)
+                } else {
+                    Err(out_of_range(self))
+                }
+            }
+        }
+    )*};
+}
+
+impl_number_for_float!(f32, f64);
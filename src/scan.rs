@@ -0,0 +1,154 @@
+//! Locating durations embedded in free text.
+
+use crate::unicode::is_cjk;
+use crate::{CJK_UNITS, NUMBER, ParseOptions, UNITS, parse_parts};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// A duration found inside a larger string by [`find_durations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationMatch<'t> {
+    /// Byte offset of the first character of the match.
+    pub start: usize,
+    /// Byte offset just past the last character of the match.
+    pub end: usize,
+    /// The matched text, e.g. `"45 seconds"`.
+    pub text: &'t str,
+    /// The matched duration in milliseconds.
+    pub millis: i64,
+}
+
+impl DurationMatch<'_> {
+    /// The byte range of the match within the searched text.
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// Iterator over the durations found in a string.
+///
+/// Created by [`find_durations`] and [`find_durations_with`].
+#[derive(Debug)]
+pub struct Durations<'t> {
+    text: &'t str,
+    pos: usize,
+    opts: ParseOptions,
+}
+
+/// Find every duration embedded in `text`.
+///
+/// Unlike `parse()`, the input is not required to consist of a single duration:
+/// each occurrence of a number directly followed by a unit (e.g. "45 seconds",
+/// "2h") is yielded in order of appearance. Numbers with units that follow
+/// each other, as in "1h30m" or "1h 30m", are one duration, as in `parse()`.
+/// Bare numbers without a unit are skipped, as are numbers glued to
+/// surrounding words (e.g. "v2s" or "5mode").
+///
+/// # Examples
+///
+/// ```
+/// use millis::find_durations;
+///
+/// let text = "restarted after 45 seconds, then waited 1h 30m";
+/// let found: Vec<_> = find_durations(text).collect();
+///
+/// assert_eq!(found.len(), 2);
+/// assert_eq!(found[0].text, "45 seconds");
+/// assert_eq!(found[0].range(), 16..26);
+/// assert_eq!(found[0].millis, 45000);
+/// assert_eq!(found[1].text, "1h 30m");
+/// assert_eq!(found[1].millis, 5400000);
+/// ```
+pub fn find_durations(text: &str) -> Durations<'_> {
+    find_durations_with(text, None)
+}
+
+/// Find every duration embedded in `text`, interpreting units with `options`.
+///
+/// Occurrences whose unit is rejected by the options (e.g. "5 Hours" when
+/// `case_sensitive` is set) are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{find_durations_with, ParseOptions};
+///
+/// let java = ParseOptions::new().case_sensitive(true);
+/// let found: Vec<_> = find_durations_with("keep 3M, poll 5m", Some(java)).collect();
+///
+/// assert_eq!(found[0].millis, 7889400000);
+/// assert_eq!(found[1].millis, 300000);
+/// ```
+pub fn find_durations_with(text: &str, options: Option<ParseOptions>) -> Durations<'_> {
+    Durations {
+        text,
+        pos: 0,
+        opts: options.unwrap_or_default(),
+    }
+}
+
+impl<'t> Iterator for Durations<'t> {
+    type Item = DurationMatch<'t>;
+
+    fn next(&mut self) -> Option<DurationMatch<'t>> {
+        static RE: OnceLock<Regex> = OnceLock::new();
+        // Parts before the last may be followed directly by the next number,
+        // as in "1h30m"; the last must end the word
+        let re = RE.get_or_init(|| {
+            Regex::new(&format!(
+                r"(?i)(?:{NUMBER}\s*(?:{UNITS}|{CJK_UNITS})\s*)*{NUMBER}\s*(?:(?:{UNITS})\b|{CJK_UNITS})"
+            ))
+            .unwrap()
+        });
+
+        while let Some(whole) = re.find_at(self.text, self.pos) {
+            self.pos = whole.end();
+
+            let before = &self.text[..whole.start()];
+            if ends_in_word(before) {
+                continue;
+            }
+
+            // A leading minus only counts when it is not a dash between words
+            // or numbers, as in "10-15 minutes".
+            let mut start = whole.start();
+            let mut negative = false;
+            if let Some(rest) = before.strip_suffix(['-', '−']) {
+                if !ends_in_word(rest) {
+                    start = rest.len();
+                    negative = true;
+                }
+            }
+
+            let Ok(mut parsed) = parse_parts(whole.as_str(), &self.opts) else {
+                continue;
+            };
+            parsed.negative = negative;
+            // Values beyond the i64 range are skipped, as `parse()` rejects them
+            let Some(millis) = parsed
+                .round(1, self.opts.rounding)
+                .and_then(|total| i64::try_from(total).ok())
+            else {
+                continue;
+            };
+
+            return Some(DurationMatch {
+                start,
+                end: whole.end(),
+                text: &self.text[start..whole.end()],
+                millis,
+            });
+        }
+
+        self.pos = self.text.len();
+        None
+    }
+}
+
+/// Whether `text` ends in a character that would glue a following number to
+/// the preceding word. CJK characters do not, as those scripts omit spaces.
+fn ends_in_word(text: &str) -> bool {
+    text.chars()
+        .next_back()
+        .is_some_and(|c| (c.is_alphanumeric() && !is_cjk(c)) || c == '_' || c == '.')
+}
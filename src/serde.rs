@@ -0,0 +1,304 @@
+//! Serde support for durations, behind the `serde` feature.
+//!
+//! Use these modules with `#[serde(with = "...")]` on `i64`, `u64`, [`Millis`]
+//! and `std::time::Duration` fields, or an `Option` of any of them. Every
+//! module deserializes the same input: a number of milliseconds, a time string
+//! such as "1h30m" or "2 days", or an ISO 8601 duration such as "PT1H30M".
+//! The module chosen sets the output:
+//!
+//! * [`millis::serde`](self) and [`short`] - the shortest exact string, as
+//!   `format_exact()` gives, such as "90m"
+//! * [`long`] - days, hours, minutes, seconds and milliseconds with their
+//!   names, such as "1 hour 30 minutes"
+//! * [`iso`] - an ISO 8601 duration in days and time, such as "PT1H30M"
+//! * [`number`] - a plain number of milliseconds
+//!
+//! Every output reads back to the same value. Sub-millisecond parts of a
+//! `Duration` are dropped when it is serialized, and a missing `Option` field
+//! also needs `#[serde(default)]`.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use std::time::Duration;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "millis::serde")]
+//!     timeout: i64,
+//!     #[serde(with = "millis::serde::long")]
+//!     retry: Duration,
+//!     #[serde(with = "millis::serde::iso", default)]
+//!     idle: Option<u64>,
+//! }
+//!
+//! let json = r#"{ "timeout": "1.5h", "retry": 30000, "idle": "PT5M" }"#;
+//! let config: Config = serde_json::from_str(json).unwrap();
+//! assert_eq!(config.timeout, 5400000);
+//! assert_eq!(config.retry, Duration::from_secs(30));
+//! assert_eq!(config.idle, Some(300000));
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&config).unwrap(),
+//!     r#"{"timeout":"90m","retry":"30 seconds","idle":"PT5M"}"#
+//! );
+//! ```
+
+use crate::exact::exact;
+use crate::iso::{format_iso, is_iso, parse_iso};
+use crate::number::Number;
+use crate::{Millis, parse_i128};
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::ser::{self, Serialize, Serializer};
+use field::{Field, Format};
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+mod field {
+    use super::*;
+
+    /// The output of a serde module
+    #[derive(Clone, Copy)]
+    pub enum Format {
+        Short,
+        Long,
+        Iso,
+        Number,
+    }
+
+    /// A field type the serde modules accept
+    pub trait Field: Sized {
+        /// Serialize the value in `format`
+        fn serialize_as<S: Serializer>(&self, format: Format, s: S) -> Result<S::Ok, S::Error>;
+
+        /// Deserialize a number or string into the value
+        fn deserialize_from<'de, D: Deserializer<'de>>(d: D) -> Result<Self, D::Error>;
+    }
+}
+
+/// Serialize `ms` in `format`
+fn write<S: Serializer>(ms: i128, format: Format, s: S) -> Result<S::Ok, S::Error> {
+    match format {
+        Format::Short => s.serialize_str(&exact(ms, false)),
+        Format::Long => s.serialize_str(&exact(ms, true)),
+        Format::Iso => s.serialize_str(&format_iso(ms)),
+        Format::Number => {
+            if let Ok(ms) = i64::try_from(ms) {
+                s.serialize_i64(ms)
+            } else if let Ok(ms) = u64::try_from(ms) {
+                s.serialize_u64(ms)
+            } else {
+                s.serialize_i128(ms)
+            }
+        }
+    }
+}
+
+/// Accepts a number of milliseconds or a duration string
+struct MillisVisitor;
+
+impl Visitor<'_> for MillisVisitor {
+    type Value = i128;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a number of milliseconds or a duration string")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<i128, E> {
+        Ok(i128::from(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<i128, E> {
+        Ok(i128::from(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<i128, E> {
+        Ok(v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<i128, E> {
+        i128::try_from(v).map_err(|_| E::custom(format!("Duration out of range. value={:?}", v)))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<i128, E> {
+        v.to_i64().map(i128::from).map_err(E::custom)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<i128, E> {
+        let v = v.trim();
+        if is_iso(v) {
+            parse_iso(v)
+        } else {
+            parse_i128(v)
+        }
+        .map_err(E::custom)
+    }
+}
+
+/// Deserialize milliseconds and convert them with `convert`
+fn read<'de, D, T>(d: D, convert: impl FnOnce(i128) -> Result<T, String>) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    convert(d.deserialize_any(MillisVisitor)?).map_err(de::Error::custom)
+}
+
+impl Field for i64 {
+    fn serialize_as<S: Serializer>(&self, format: Format, s: S) -> Result<S::Ok, S::Error> {
+        write(i128::from(*self), format, s)
+    }
+
+    fn deserialize_from<'de, D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        read(d, |ms| {
+            i64::try_from(ms).map_err(|_| format!("Duration out of range. value={:?}", ms))
+        })
+    }
+}
+
+impl Field for u64 {
+    fn serialize_as<S: Serializer>(&self, format: Format, s: S) -> Result<S::Ok, S::Error> {
+        write(i128::from(*self), format, s)
+    }
+
+    fn deserialize_from<'de, D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        read(d, |ms| {
+            if ms < 0 {
+                return Err(format!("Negative duration. value={:?}", ms));
+            }
+            u64::try_from(ms).map_err(|_| format!("Duration out of range. value={:?}", ms))
+        })
+    }
+}
+
+impl Field for Millis {
+    fn serialize_as<S: Serializer>(&self, format: Format, s: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_as(format, s)
+    }
+
+    fn deserialize_from<'de, D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        i64::deserialize_from(d).map(Millis)
+    }
+}
+
+/// Sub-millisecond parts are dropped when serializing.
+impl Field for Duration {
+    fn serialize_as<S: Serializer>(&self, format: Format, s: S) -> Result<S::Ok, S::Error> {
+        let ms = i128::try_from(self.as_millis())
+            .map_err(|_| ser::Error::custom(format!("Duration out of range. value={:?}", self)))?;
+        write(ms, format, s)
+    }
+
+    fn deserialize_from<'de, D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        read(d, |ms| {
+            if ms < 0 {
+                return Err(format!("Negative duration. value={:?}", ms));
+            }
+            let secs = u64::try_from(ms / 1000)
+                .map_err(|_| format!("Duration out of range. value={:?}", ms))?;
+            Ok(Duration::new(secs, (ms % 1000) as u32 * 1_000_000))
+        })
+    }
+}
+
+/// A value with the format to serialize it in
+struct As<'a, T>(&'a T, Format);
+
+impl<T: Field> Serialize for As<'_, T> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_as(self.1, s)
+    }
+}
+
+/// Accepts a missing value or one of `T`
+struct OptionVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Field> Visitor<'de> for OptionVisitor<T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a number of milliseconds, a duration string or nothing")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<T>, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Option<T>, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Option<T>, D::Error> {
+        T::deserialize_from(d).map(Some)
+    }
+}
+
+impl<T: Field> Field for Option<T> {
+    fn serialize_as<S: Serializer>(&self, format: Format, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => s.serialize_some(&As(value, format)),
+            None => s.serialize_none(),
+        }
+    }
+
+    fn deserialize_from<'de, D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_option(OptionVisitor(PhantomData))
+    }
+}
+
+/// Serialize a duration as the shortest exact string, such as "90m".
+pub fn serialize<T: Field, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    value.serialize_as(Format::Short, serializer)
+}
+
+/// Deserialize a duration from a number of milliseconds or a string.
+pub fn deserialize<'de, T: Field, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    T::deserialize_from(deserializer)
+}
+
+macro_rules! format_module {
+    ($name:ident, $format:ident, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $name {
+            use super::{Field, Format};
+            use ::serde::{Deserializer, Serializer};
+
+            /// Serialize a duration in this module's format.
+            pub fn serialize<T: Field, S: Serializer>(
+                value: &T,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                value.serialize_as(Format::$format, serializer)
+            }
+
+            /// Deserialize a duration from a number of milliseconds or a string.
+            pub fn deserialize<'de, T: Field, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<T, D::Error> {
+                T::deserialize_from(deserializer)
+            }
+        }
+    };
+}
+
+format_module!(
+    short,
+    Short,
+    "Serialize as the shortest exact string, such as \"90m\"."
+);
+format_module!(
+    long,
+    Long,
+    "Serialize with unit names, such as \"1 hour 30 minutes\"."
+);
+format_module!(
+    iso,
+    Iso,
+    "Serialize as an ISO 8601 duration, such as \"PT1H30M\"."
+);
+format_module!(
+    number,
+    Number,
+    "Serialize as a plain number of milliseconds."
+);
@@ -0,0 +1,171 @@
+//! strftime-style templates such as "%H:%M:%S".
+
+use crate::{Breakdown, Unit};
+use std::fmt::Write;
+
+/// A field of a template: the unit shown, whether it is padded by default,
+/// and the width it is padded to
+struct Field {
+    unit: Unit,
+    padded: bool,
+    width: usize,
+}
+
+/// Look up the field for a specifier character
+fn field(spec: char) -> Option<Field> {
+    let (unit, padded, width) = match spec {
+        'd' => (Unit::Days, false, 2),
+        'H' => (Unit::Hours, true, 2),
+        'h' => (Unit::Hours, false, 2),
+        'M' => (Unit::Minutes, true, 2),
+        'm' => (Unit::Minutes, false, 2),
+        'S' => (Unit::Seconds, true, 2),
+        's' => (Unit::Seconds, false, 2),
+        'L' => (Unit::Milliseconds, true, 3),
+        _ => return None,
+    };
+    Some(Field {
+        unit,
+        padded,
+        width,
+    })
+}
+
+/// A piece of a parsed template
+enum Piece<'t> {
+    Literal(&'t str),
+    Field(Field, Option<char>),
+}
+
+/// Format the given milliseconds with a strftime-style template.
+///
+/// Specifiers:
+///
+/// * `%d` - days
+/// * `%H`, `%M`, `%S` - hours, minutes and seconds, zero-padded to 2 digits
+/// * `%h`, `%m`, `%s` - hours, minutes and seconds, not padded
+/// * `%L` - milliseconds, zero-padded to 3 digits
+/// * `%T` - same as `%H:%M:%S`
+/// * `%%` - a literal `%`
+///
+/// A flag between `%` and the specifier changes the padding: `-` for none,
+/// `0` for zeros and `_` for spaces, to 2 digits (3 for `%L`).
+///
+/// The largest field used carries everything above it, so `%H` shows 49 for
+/// 49 hours when `%d` is not used, and time below the smallest field is
+/// dropped. Negative durations start with `-`, unless every field is zero.
+///
+/// # Errors
+///
+/// Returns an error for an unknown specifier or a trailing `%`.
+///
+/// # Examples
+///
+/// ```
+/// use millis::format_template;
+///
+/// assert_eq!(format_template(5430000, "%H:%M:%S").unwrap(), "01:30:30");
+/// assert_eq!(format_template(176400000, "%dd %hh").unwrap(), "2d 1h");
+/// assert_eq!(format_template(176400000, "%T").unwrap(), "49:00:00");
+/// assert_eq!(format_template(-61500, "%m:%S.%L").unwrap(), "-1:01.500");
+/// assert_eq!(format_template(90000, "%_H|%-S|%%").unwrap(), " 0|30|%");
+/// assert!(format_template(1000, "%Q").is_err());
+/// ```
+pub fn format_template(ms: i64, template: &str) -> Result<String, String> {
+    let pieces = parse_template(template)?;
+    let units = pieces.iter().filter_map(|piece| match piece {
+        Piece::Field(field, _) => Some(field.unit),
+        Piece::Literal(_) => None,
+    });
+    let (Some(largest), Some(smallest)) = (units.clone().max(), units.min()) else {
+        // Nothing to fill in
+        return Ok(pieces
+            .iter()
+            .map(|piece| match piece {
+                Piece::Literal(text) => *text,
+                Piece::Field(..) => "",
+            })
+            .collect());
+    };
+
+    let breakdown = Breakdown::with_units(ms, largest, smallest);
+    let mut out = String::new();
+    if breakdown.negative && !is_zero(&breakdown) {
+        out.push('-');
+    }
+    for piece in &pieces {
+        match piece {
+            Piece::Literal(text) => out.push_str(text),
+            Piece::Field(field, flag) => {
+                let value = breakdown.component(field.unit);
+                // Writing to a `String` cannot fail
+                let _ = match flag.unwrap_or(if field.padded { '0' } else { '-' }) {
+                    '0' => write!(out, "{:0width$}", value, width = field.width),
+                    '_' => write!(out, "{:width$}", value, width = field.width),
+                    _ => write!(out, "{}", value),
+                };
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Whether every component of `breakdown` is zero
+fn is_zero(breakdown: &Breakdown) -> bool {
+    Breakdown {
+        negative: false,
+        ..*breakdown
+    } == Breakdown::default()
+}
+
+/// Split a template into literal text and fields
+fn parse_template(template: &str) -> Result<Vec<Piece<'_>>, String> {
+    let mut pieces = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('%') {
+        if start > 0 {
+            pieces.push(Piece::Literal(&rest[..start]));
+        }
+        let mut chars = rest[start + 1..].chars();
+        let mut spec = chars.next();
+        let flag = match spec {
+            Some(c @ ('-' | '_' | '0')) => {
+                spec = chars.next();
+                Some(c)
+            }
+            _ => None,
+        };
+
+        match spec {
+            Some('%') if flag.is_none() => pieces.push(Piece::Literal("%")),
+            Some('T') => {
+                for spec in ['H', ':', 'M', ':', 'S'] {
+                    pieces.push(match field(spec) {
+                        Some(field) => Piece::Field(field, flag),
+                        None => Piece::Literal(":"),
+                    });
+                }
+            }
+            Some(c) => match field(c) {
+                Some(field) => pieces.push(Piece::Field(field, flag)),
+                None => {
+                    return Err(format!(
+                        "Unknown specifier \"%{}\" in template. template={:?}",
+                        c, template
+                    ));
+                }
+            },
+            None => {
+                return Err(format!(
+                    "Template ends with an incomplete specifier. template={:?}",
+                    template
+                ));
+            }
+        }
+        rest = chars.as_str();
+    }
+    if !rest.is_empty() {
+        pieces.push(Piece::Literal(rest));
+    }
+    Ok(pieces)
+}
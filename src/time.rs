@@ -0,0 +1,147 @@
+//! `time` support, behind the `time` feature.
+
+use crate::{Millis, Options, ToMillis, format_i128, parse_nanos};
+use ::time::{Duration, OffsetDateTime};
+
+/// Parse the given string into a signed `time::Duration`.
+///
+/// The grammar is the same as `parse()`, but the value is kept to the
+/// nanosecond instead of being rounded to whole milliseconds, with ties
+/// rounded away from zero.
+///
+/// # Errors
+///
+/// Returns an error for invalid input or a value outside the range of
+/// `time::Duration`.
+///
+/// # Examples
+///
+/// ```
+/// use millis::parse_time_duration;
+/// use time::Duration;
+///
+/// assert_eq!(parse_time_duration("1h 30m").unwrap(), Duration::minutes(90));
+/// assert_eq!(parse_time_duration("-2.5s").unwrap(), Duration::milliseconds(-2500));
+/// assert_eq!(parse_time_duration("1.5 µs").unwrap(), Duration::nanoseconds(1500));
+/// assert!(parse_time_duration("invalid").is_err());
+/// ```
+pub fn parse_time_duration(s: &str) -> Result<Duration, String> {
+    time_duration(parse_nanos(s)?).ok_or_else(|| format!("Duration out of range. value={:?}", s))
+}
+
+/// Build a `time::Duration` from nanoseconds, if it is in range
+fn time_duration(nanos: i128) -> Option<Duration> {
+    let secs = i64::try_from(nanos / 1_000_000_000).ok()?;
+    // The remainder has the same sign as the seconds, so nothing carries over
+    Some(Duration::new(secs, (nanos % 1_000_000_000) as i32))
+}
+
+/// Format a `time::Duration` with the given options, like `format()`.
+///
+/// The duration is rounded to the nearest millisecond first, with ties rounded
+/// away from zero.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{format_time_duration, Options};
+/// use time::Duration;
+///
+/// assert_eq!(format_time_duration(Duration::minutes(90), None), "2h");
+/// assert_eq!(
+///     format_time_duration(Duration::seconds(-90), Some(Options::new().long(true))),
+///     "-2 minutes"
+/// );
+/// assert_eq!(format_time_duration(Duration::microseconds(1500), None), "2ms");
+/// ```
+pub fn format_time_duration(duration: Duration, options: Option<Options>) -> String {
+    format_i128(duration_millis(duration), options)
+}
+
+/// The duration in milliseconds, rounded half away from zero
+fn duration_millis(duration: Duration) -> i128 {
+    let nanos = duration.whole_nanoseconds();
+    let half = if nanos < 0 { -500_000 } else { 500_000 };
+    (nanos + half) / 1_000_000
+}
+
+/// Implementation for time::Duration - converts the duration to a formatted
+/// string
+impl ToMillis for Duration {
+    type Output = Result<String, String>;
+
+    fn to_millis(self) -> Result<String, String> {
+        Ok(format_time_duration(self, None))
+    }
+}
+
+impl From<Millis> for Duration {
+    fn from(ms: Millis) -> Duration {
+        Duration::milliseconds(ms.0)
+    }
+}
+
+/// Sub-millisecond parts of the `time::Duration` are dropped.
+impl TryFrom<Duration> for Millis {
+    type Error = String;
+
+    fn try_from(duration: Duration) -> Result<Millis, String> {
+        i64::try_from(duration.whole_milliseconds())
+            .map(Millis)
+            .map_err(|_| format!("Duration out of range. value={:?}", duration))
+    }
+}
+
+/// Add the duration in the given string to an `OffsetDateTime`.
+///
+/// The duration is parsed like `parse_time_duration()`, so months and years
+/// have the fixed lengths used by `parse()`, and a leading `-` moves back in
+/// time. The offset is kept.
+///
+/// # Errors
+///
+/// Returns an error for invalid input or a result outside the range of
+/// `OffsetDateTime`.
+///
+/// # Examples
+///
+/// ```
+/// use millis::{add_duration, sub_duration};
+/// use time::macros::datetime;
+///
+/// let start = datetime!(2024-01-01 12:00 UTC);
+/// assert_eq!(add_duration(start, "1d 6h").unwrap(), datetime!(2024-01-02 18:00 UTC));
+/// assert_eq!(add_duration(start, "-30m").unwrap(), datetime!(2024-01-01 11:30 UTC));
+/// assert_eq!(sub_duration(start, "1 week").unwrap(), datetime!(2023-12-25 12:00 UTC));
+/// ```
+pub fn add_duration(date_time: OffsetDateTime, s: &str) -> Result<OffsetDateTime, String> {
+    date_time
+        .checked_add(parse_time_duration(s)?)
+        .ok_or_else(|| format!("Date out of range. value={:?}", s))
+}
+
+/// Subtract the duration in the given string from an `OffsetDateTime`.
+///
+/// This is `add_duration()` in the other direction, so a leading `-` moves
+/// forward in time.
+///
+/// # Errors
+///
+/// Returns an error for invalid input or a result outside the range of
+/// `OffsetDateTime`.
+///
+/// # Examples
+///
+/// ```
+/// use millis::sub_duration;
+/// use time::macros::datetime;
+///
+/// let end = datetime!(2024-03-01 00:00 +02:00);
+/// assert_eq!(sub_duration(end, "1.5h").unwrap(), datetime!(2024-02-29 22:30 +02:00));
+/// assert!(sub_duration(end, "invalid").is_err());
+/// ```
+pub fn sub_duration(date_time: OffsetDateTime, s: &str) -> Result<OffsetDateTime, String> {
+    date_time
+        .checked_sub(parse_time_duration(s)?)
+        .ok_or_else(|| format!("Date out of range. value={:?}", s))
+}
@@ -0,0 +1,160 @@
+//! Normalization of Unicode numbers pasted from documents.
+
+use regex::Regex;
+use std::sync::OnceLock;
+
+// Vulgar fraction characters as (numerator, denominator)
+const FRACTIONS: &[(char, u8, u8)] = &[
+    ('½', 1, 2),
+    ('⅓', 1, 3),
+    ('⅔', 2, 3),
+    ('¼', 1, 4),
+    ('¾', 3, 4),
+    ('⅕', 1, 5),
+    ('⅖', 2, 5),
+    ('⅗', 3, 5),
+    ('⅘', 4, 5),
+    ('⅙', 1, 6),
+    ('⅚', 5, 6),
+    ('⅐', 1, 7),
+    ('⅛', 1, 8),
+    ('⅜', 3, 8),
+    ('⅝', 5, 8),
+    ('⅞', 7, 8),
+    ('⅑', 1, 9),
+    ('⅒', 1, 10),
+    ('↉', 0, 3),
+];
+
+/// Value of a Unicode decimal digit, e.g. `'５'` or `'٥'` is 5.
+///
+/// Digits are recognized with the regex `\d`, so this agrees with the parser
+/// on every digit it matches. Unicode encodes each set of decimal digits as a
+/// run of ten code points from 0 to 9, so the value is the distance from the
+/// start of the run, modulo ten for sets placed back to back.
+fn digit_value(c: char) -> Option<u8> {
+    if c.is_ascii_digit() {
+        return Some(c as u8 - b'0');
+    }
+    if !is_digit(c) {
+        return None;
+    }
+    let preceding = (1..)
+        .map_while(|back| char::from_u32(c as u32 - back))
+        .take_while(|&d| is_digit(d))
+        .count();
+    Some((preceding % 10) as u8)
+}
+
+/// Whether `c` is a Unicode decimal digit (Nd), as matched by `\d`
+fn is_digit(c: char) -> bool {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| Regex::new(r"^\d$").unwrap());
+    re.is_match(c.encode_utf8(&mut [0; 4]))
+}
+
+/// Value of a vulgar fraction character, e.g. `'¼'` is `(1, 4)`
+pub(crate) fn fraction_value(c: char) -> Option<(u8, u8)> {
+    FRACTIONS
+        .iter()
+        .find(|&&(f, _, _)| f == c)
+        .map(|&(_, n, d)| (n, d))
+}
+
+/// Convert a matched number to `f64`.
+///
+/// Unicode digits are folded to ASCII, `−` (U+2212) is read as a minus sign and
+/// a trailing vulgar fraction is added to the whole part, so `"1¼"` is 1.25.
+pub(crate) fn parse_number(value: &str) -> Option<f64> {
+    let mut ascii = String::with_capacity(value.len());
+    let mut fraction = 0.0;
+    for c in value.chars() {
+        match c {
+            '-' | '−' => ascii.push('-'),
+            '.' => ascii.push('.'),
+            _ => match fraction_value(c) {
+                Some((n, d)) => fraction = f64::from(n) / f64::from(d),
+                None => ascii.push(char::from(b'0' + digit_value(c)?)),
+            },
+        }
+    }
+
+    let (negative, digits) = match ascii.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, ascii.as_str()),
+    };
+    let whole: f64 = if digits.is_empty() {
+        0.0
+    } else {
+        digits.parse().ok()?
+    };
+    let value = whole + fraction;
+    Some(if negative { -value } else { value })
+}
+
+/// Digits kept after the decimal point when parsing exactly
+pub(crate) const FRACTION_DIGITS: u32 = 24;
+
+/// A matched number split into exact parts, e.g. `"12.5"` is 12 and
+/// 500000000000000000000000 / 10^24
+pub(crate) struct ExactNumber {
+    /// The whole part, or `None` if it does not fit in a `u128`
+    pub(crate) whole: Option<u128>,
+    /// The first `FRACTION_DIGITS` digits after the point, as a numerator of
+    /// 10^`FRACTION_DIGITS`
+    pub(crate) decimals: u128,
+    /// Whether a non-zero digit was dropped after those
+    pub(crate) sticky: bool,
+    /// A trailing vulgar fraction, as `(numerator, denominator)`
+    pub(crate) fraction: Option<(u8, u8)>,
+}
+
+/// Split an unsigned matched number into exact parts.
+pub(crate) fn split_number(value: &str) -> Option<ExactNumber> {
+    let mut number = ExactNumber {
+        whole: Some(0),
+        decimals: 0,
+        sticky: false,
+        fraction: None,
+    };
+    // Digits kept after the point, once one is seen
+    let mut kept: Option<u32> = None;
+    for c in value.chars() {
+        if c == '.' {
+            kept = Some(0);
+            continue;
+        }
+        if let Some(fraction) = fraction_value(c) {
+            number.fraction = Some(fraction);
+            continue;
+        }
+        let digit = u128::from(digit_value(c)?);
+        match kept {
+            None => {
+                number.whole = number
+                    .whole
+                    .and_then(|w| w.checked_mul(10)?.checked_add(digit))
+            }
+            Some(k) if k < FRACTION_DIGITS => {
+                number.decimals = number.decimals * 10 + digit;
+                kept = Some(k + 1);
+            }
+            Some(_) => number.sticky |= digit != 0,
+        }
+    }
+    if let Some(k) = kept {
+        number.decimals *= 10u128.pow(FRACTION_DIGITS - k);
+    }
+    Some(number)
+}
+
+/// Whether `c` belongs to a script written without spaces between words
+pub(crate) fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}'     // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}'   // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}'   // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}'   // Hangul Syllables
+        | '\u{F900}'..='\u{FAFF}'   // CJK Compatibility Ideographs
+    )
+}
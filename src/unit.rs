@@ -0,0 +1,168 @@
+//! Units of time and their names.
+
+use crate::{CENT, D, DEC, FN, H, M, MILL, MO, MPolicy, ParseOptions, S, W, Y};
+
+/// A unit of time, ordered from shortest to longest.
+///
+/// Months and years are fixed-length averages: a year is 365.25 days and a
+/// month is one twelfth of that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    /// One thousandth of a millisecond.
+    Microseconds,
+    /// The base unit of this crate.
+    Milliseconds,
+    /// 1000 milliseconds.
+    Seconds,
+    /// 60 seconds.
+    Minutes,
+    /// 60 minutes.
+    Hours,
+    /// 24 hours.
+    Days,
+    /// 7 days.
+    Weeks,
+    /// 14 days.
+    Fortnights,
+    /// One twelfth of a year, about 30.44 days.
+    Months,
+    /// 365.25 days.
+    Years,
+    /// 10 years.
+    Decades,
+    /// 100 years.
+    Centuries,
+    /// 1000 years.
+    Millennia,
+}
+
+impl Unit {
+    /// The number of milliseconds in one of this unit.
+    ///
+    /// ```
+    /// use millis::Unit;
+    ///
+    /// assert_eq!(Unit::Hours.millis(), 3600000.0);
+    /// assert_eq!(Unit::Microseconds.millis(), 0.001);
+    /// ```
+    pub const fn millis(self) -> f64 {
+        match self {
+            Unit::Microseconds => 0.001,
+            Unit::Milliseconds => 1.0,
+            Unit::Seconds => S,
+            Unit::Minutes => M,
+            Unit::Hours => H,
+            Unit::Days => D,
+            Unit::Weeks => W,
+            Unit::Fortnights => FN,
+            Unit::Months => MO,
+            Unit::Years => Y,
+            Unit::Decades => DEC,
+            Unit::Centuries => CENT,
+            Unit::Millennia => MILL,
+        }
+    }
+
+    /// Length of one of this unit as the ratio `(numerator, denominator)` of
+    /// milliseconds, which is exact for every unit
+    pub(crate) const fn ratio(self) -> (u128, u128) {
+        match self {
+            Unit::Microseconds => (1, 1000),
+            unit => (unit.millis() as u128, 1),
+        }
+    }
+
+    /// Symbol used by the narrow style. Fortnights and the extended units
+    /// have none, and are written with their names instead.
+    pub(crate) const fn symbol(self) -> Option<&'static str> {
+        match self {
+            Unit::Microseconds => Some("µs"),
+            Unit::Milliseconds => Some("ms"),
+            Unit::Seconds => Some("s"),
+            Unit::Minutes => Some("m"),
+            Unit::Hours => Some("h"),
+            Unit::Days => Some("d"),
+            Unit::Weeks => Some("w"),
+            Unit::Months => Some("mo"),
+            Unit::Years => Some("y"),
+            Unit::Fortnights | Unit::Decades | Unit::Centuries | Unit::Millennia => None,
+        }
+    }
+
+    /// Singular and plural abbreviations used by the short style. Units
+    /// without a common abbreviation use their names.
+    pub(crate) const fn abbreviations(self) -> (&'static str, &'static str) {
+        match self {
+            Unit::Microseconds => ("µs", "µs"),
+            Unit::Milliseconds => ("ms", "ms"),
+            Unit::Seconds => ("sec", "secs"),
+            Unit::Minutes => ("min", "mins"),
+            Unit::Hours => ("hr", "hrs"),
+            Unit::Days => ("day", "days"),
+            Unit::Weeks => ("wk", "wks"),
+            Unit::Months => ("mth", "mths"),
+            Unit::Years => ("yr", "yrs"),
+            unit => unit.names(),
+        }
+    }
+
+    /// Singular and plural names used by the long format
+    pub(crate) const fn names(self) -> (&'static str, &'static str) {
+        match self {
+            Unit::Microseconds => ("microsecond", "microseconds"),
+            Unit::Milliseconds => ("millisecond", "milliseconds"),
+            Unit::Seconds => ("second", "seconds"),
+            Unit::Minutes => ("minute", "minutes"),
+            Unit::Hours => ("hour", "hours"),
+            Unit::Days => ("day", "days"),
+            Unit::Weeks => ("week", "weeks"),
+            Unit::Fortnights => ("fortnight", "fortnights"),
+            Unit::Months => ("month", "months"),
+            Unit::Years => ("year", "years"),
+            Unit::Decades => ("decade", "decades"),
+            Unit::Centuries => ("century", "centuries"),
+            Unit::Millennia => ("millennium", "millennia"),
+        }
+    }
+
+    /// Look up a unit by one of its names, as matched by the parser
+    pub(crate) fn from_name(name: &str, opts: &ParseOptions) -> Option<Unit> {
+        if opts.m_policy == MPolicy::Reject && name.eq_ignore_ascii_case("m") {
+            return None;
+        }
+        if opts.case_sensitive {
+            if name == "M" {
+                return Some(Unit::Months);
+            }
+            if name.chars().any(char::is_uppercase) {
+                return None;
+            }
+        }
+
+        let unit = match name.to_lowercase().as_str() {
+            "millennia" | "millennium" => Unit::Millennia,
+            "centuries" | "century" => Unit::Centuries,
+            "decades" | "decade" => Unit::Decades,
+            "years" | "year" | "yrs" | "yr" | "y" => Unit::Years,
+            "months" | "month" | "mths" | "mth" | "mo" | "个月" | "個月" | "ヶ月" | "か月" => {
+                Unit::Months
+            }
+            "fortnights" | "fortnight" => Unit::Fortnights,
+            "weeks" | "week" | "wks" | "wk" | "w" | "星期" | "周" | "週" => Unit::Weeks,
+            "days" | "day" | "d" | "天" => Unit::Days,
+            "hours" | "hour" | "hrs" | "hr" | "h" | "小时" | "小時" | "時間" => Unit::Hours,
+            "minutes" | "minute" | "mins" | "min" | "m" | "分钟" | "分鐘" | "分" => {
+                Unit::Minutes
+            }
+            "seconds" | "second" | "secs" | "sec" | "s" | "秒" => Unit::Seconds,
+            "milliseconds" | "millisecond" | "msecs" | "msec" | "ms" | "毫秒" => {
+                Unit::Milliseconds
+            }
+            "microseconds" | "microsecond" | "usecs" | "usec" | "us" | "µs" | "μs" => {
+                Unit::Microseconds
+            }
+            _ => return None,
+        };
+        Some(unit)
+    }
+}
@@ -0,0 +1,157 @@
+use millis::{Breakdown, Unit};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86400000;
+
+    // ============================================================================
+    // Test Breakdown::new(number)
+    // ============================================================================
+
+    mod test_breakdown_new {
+        use super::*;
+
+        #[test]
+        fn should_split_into_components() {
+            // should split milliseconds into components
+            let b = Breakdown::new(93784005);
+            assert_eq!(
+                b,
+                Breakdown {
+                    negative: false,
+                    days: 1,
+                    hours: 2,
+                    minutes: 3,
+                    seconds: 4,
+                    milliseconds: 5,
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn should_use_weeks_months_and_years() {
+            // should use weeks, months and years
+            let b = Breakdown::new(31557600000 + 2629800000 + 2 * 7 * DAY + DAY);
+            assert_eq!((b.years, b.months, b.weeks, b.days), (1, 1, 2, 1));
+            assert_eq!(
+                (b.hours, b.minutes, b.seconds, b.milliseconds),
+                (0, 0, 0, 0)
+            );
+        }
+
+        #[test]
+        fn should_keep_the_sign_separately() {
+            // should keep the sign separately from the components
+            let b = Breakdown::new(-90061001);
+            assert!(b.negative);
+            assert_eq!((b.days, b.hours, b.minutes, b.seconds), (1, 1, 1, 1));
+            assert_eq!(b.milliseconds, 1);
+        }
+
+        #[test]
+        fn should_support_zero() {
+            // should support zero
+            assert_eq!(Breakdown::new(0), Breakdown::default());
+            assert_eq!(Breakdown::from(0), Breakdown::default());
+        }
+
+        #[test]
+        fn should_support_the_full_i64_range() {
+            // should support i64::MIN and i64::MAX
+            assert!(Breakdown::new(i64::MIN).negative);
+            assert_eq!(Breakdown::new(i64::MAX).years, 292271023);
+        }
+    }
+
+    // ============================================================================
+    // Test Breakdown::with_units(number, largest, smallest)
+    // ============================================================================
+
+    mod test_breakdown_with_units {
+        use super::*;
+
+        #[test]
+        fn should_carry_into_largest_unit() {
+            // should carry time above the largest unit into it
+            let b = Breakdown::with_units(3 * DAY + 3600000, Unit::Hours, Unit::Milliseconds);
+            assert_eq!((b.days, b.hours), (0, 73));
+        }
+
+        #[test]
+        fn should_drop_time_below_smallest_unit() {
+            // should truncate time below the smallest unit
+            let b = Breakdown::with_units(5999, Unit::Years, Unit::Seconds);
+            assert_eq!((b.seconds, b.milliseconds), (5, 0));
+            let b = Breakdown::with_units(-5999, Unit::Years, Unit::Seconds);
+            assert_eq!((b.negative, b.seconds), (true, 5));
+        }
+
+        #[test]
+        fn should_support_a_single_unit() {
+            // should put everything into a single unit
+            let b = Breakdown::with_units(5400000, Unit::Minutes, Unit::Minutes);
+            assert_eq!(b.minutes, 90);
+            assert_eq!((b.hours, b.seconds), (0, 0));
+        }
+
+        #[test]
+        fn should_accept_bounds_in_either_order() {
+            // should accept the bounds in either order
+            assert_eq!(
+                Breakdown::with_units(93784005, Unit::Seconds, Unit::Hours),
+                Breakdown::with_units(93784005, Unit::Hours, Unit::Seconds)
+            );
+        }
+
+        #[test]
+        fn should_clamp_units_without_components() {
+            // should clamp units without a component
+            assert_eq!(
+                Breakdown::with_units(93784005, Unit::Millennia, Unit::Microseconds),
+                Breakdown::new(93784005)
+            );
+            let b = Breakdown::with_units(15 * DAY, Unit::Fortnights, Unit::Days);
+            assert_eq!((b.weeks, b.days), (2, 1));
+        }
+    }
+
+    // ============================================================================
+    // Test Breakdown::total_millis()
+    // ============================================================================
+
+    mod test_breakdown_total_millis {
+        use super::*;
+
+        #[test]
+        fn should_sum_components() {
+            // should sum the components back into milliseconds
+            let b = Breakdown {
+                hours: 1,
+                minutes: 30,
+                ..Default::default()
+            };
+            assert_eq!(b.total_millis().unwrap(), 5400000);
+        }
+
+        #[test]
+        fn should_round_trip() {
+            // should round-trip every value when no unit is dropped
+            for ms in [0, 1, -1, 93784005, -31557600001, i64::MAX, i64::MIN] {
+                assert_eq!(Breakdown::new(ms).total_millis().unwrap(), ms);
+            }
+        }
+
+        #[test]
+        fn should_return_error_when_out_of_range() {
+            // should return an error when the total overflows i64
+            let b = Breakdown {
+                years: u64::MAX,
+                ..Default::default()
+            };
+            assert!(b.total_millis().is_err());
+        }
+    }
+}
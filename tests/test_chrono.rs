@@ -0,0 +1,183 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{FixedOffset, TimeDelta, TimeZone, Utc};
+use millis::{Options, Style, add_calendar, format_time_delta, ms, parse, parse_time_delta};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============================================================================
+    // Test parsing into TimeDelta
+    // ============================================================================
+
+    mod test_parse_time_delta {
+        use super::*;
+
+        #[test]
+        fn should_parse_like_parse() {
+            // should agree with parse() for whole milliseconds
+            for s in ["2h", "1d 12h", "-1.5 hours", "100", "1 week", "1y"] {
+                let delta = parse_time_delta(s).unwrap();
+                assert_eq!(delta.num_milliseconds(), parse(s).unwrap(), "{}", s);
+            }
+        }
+
+        #[test]
+        fn should_keep_sub_millisecond_precision() {
+            // should keep nanoseconds instead of rounding to milliseconds
+            assert_eq!(
+                parse_time_delta("1.5 µs").unwrap(),
+                TimeDelta::nanoseconds(1500)
+            );
+            assert_eq!(
+                parse_time_delta("0.1ms").unwrap(),
+                TimeDelta::microseconds(100)
+            );
+            assert_eq!(
+                parse_time_delta("1.0000005ms").unwrap(),
+                TimeDelta::nanoseconds(1000001)
+            );
+            assert_eq!(
+                parse_time_delta("-0.5 µs").unwrap(),
+                TimeDelta::nanoseconds(-500)
+            );
+        }
+
+        #[test]
+        fn should_reject_invalid_and_out_of_range() {
+            // should report errors
+            assert!(parse_time_delta("invalid").is_err());
+            assert!(parse_time_delta("").is_err());
+            assert!(parse_time_delta("1e12 years").is_err());
+            assert!(parse_time_delta("300000000 years").is_err());
+        }
+    }
+
+    // ============================================================================
+    // Test formatting TimeDelta
+    // ============================================================================
+
+    mod test_format_time_delta {
+        use super::*;
+
+        #[test]
+        fn should_format_like_format() {
+            // should format with options
+            assert_eq!(format_time_delta(TimeDelta::hours(2), None), "2h");
+            assert_eq!(format_time_delta(TimeDelta::seconds(-90), None), "-2m");
+            let short = Options::new().style(Style::Short);
+            assert_eq!(format_time_delta(TimeDelta::days(3), Some(short)), "3 days");
+        }
+
+        #[test]
+        fn should_round_to_milliseconds() {
+            // should round half away from zero
+            assert_eq!(
+                format_time_delta(TimeDelta::microseconds(1499), None),
+                "1ms"
+            );
+            assert_eq!(
+                format_time_delta(TimeDelta::microseconds(1500), None),
+                "2ms"
+            );
+            assert_eq!(
+                format_time_delta(TimeDelta::microseconds(-1500), None),
+                "-2ms"
+            );
+        }
+
+        #[test]
+        fn should_format_extremes() {
+            // should not overflow at the ends of the range
+            assert!(!format_time_delta(TimeDelta::MAX, None).is_empty());
+            assert!(format_time_delta(TimeDelta::MIN, None).starts_with('-'));
+        }
+
+        #[test]
+        fn should_work_with_ms() {
+            // should format through ms()
+            assert_eq!(ms(TimeDelta::minutes(1)).unwrap(), "1m");
+        }
+    }
+
+    // ============================================================================
+    // Test calendar-aware arithmetic
+    // ============================================================================
+
+    mod test_add_calendar {
+        use super::*;
+
+        #[test]
+        fn should_add_calendar_months() {
+            // should move by calendar months and clamp the day
+            let start = Utc.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap();
+            assert_eq!(
+                add_calendar(start, "1 month").unwrap(),
+                Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap()
+            );
+            assert_eq!(
+                add_calendar(start, "2mo").unwrap(),
+                Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap()
+            );
+            assert_eq!(
+                add_calendar(start, "1 decade").unwrap(),
+                Utc.with_ymd_and_hms(2033, 1, 31, 0, 0, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn should_add_leap_years() {
+            // should keep the time of day across years
+            let leap = Utc.with_ymd_and_hms(2024, 2, 29, 8, 30, 0).unwrap();
+            assert_eq!(
+                add_calendar(leap, "1y").unwrap(),
+                Utc.with_ymd_and_hms(2025, 2, 28, 8, 30, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn should_add_fixed_units_exactly() {
+            // should add other units as exact time
+            let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+            assert_eq!(
+                add_calendar(start, "1mo 1d 1h 1.5s").unwrap(),
+                Utc.with_ymd_and_hms(2024, 4, 2, 1, 0, 1).unwrap() + TimeDelta::milliseconds(500)
+            );
+            assert_eq!(
+                add_calendar(start, "36h").unwrap(),
+                Utc.with_ymd_and_hms(2024, 3, 2, 12, 0, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn should_subtract_negative_durations() {
+            // should subtract the whole duration
+            let start = Utc.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap();
+            assert_eq!(
+                add_calendar(start, "-1mo 12h").unwrap(),
+                Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn should_keep_time_zone() {
+            // should work with any time zone
+            let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+            let start = tz.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap();
+            let end = add_calendar(start, "1mo").unwrap();
+            assert_eq!(end, tz.with_ymd_and_hms(2024, 2, 15, 9, 0, 0).unwrap());
+            assert_eq!(end.offset(), &tz);
+        }
+
+        #[test]
+        fn should_reject_fractional_calendar_units() {
+            // should report fractional months and years
+            let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+            assert!(add_calendar(start, "1.5mo").is_err());
+            assert!(add_calendar(start, "½y").is_err());
+            assert!(add_calendar(start, "invalid").is_err());
+            assert!(add_calendar(start, "1000000 millennia").is_err());
+        }
+    }
+}
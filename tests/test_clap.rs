@@ -0,0 +1,211 @@
+#![cfg(feature = "clap")]
+
+use clap::Parser;
+use clap::error::ErrorKind;
+use millis::{DurationParser, Millis};
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timeout_parser() -> DurationParser<Duration> {
+        DurationParser::new().min(100).max(3_600_000)
+    }
+
+    #[derive(Debug, Parser)]
+    #[command(mut_arg("timeout", |arg| timeout_parser().configure(arg)))]
+    struct Cli {
+        /// Request timeout
+        #[arg(long, value_parser = timeout_parser())]
+        timeout: Option<Duration>,
+
+        /// Delay in milliseconds
+        #[arg(long, value_parser = DurationParser::<i64>::new(), allow_hyphen_values = true)]
+        delay: Option<i64>,
+
+        /// Offset as a Millis, parsed without naming a parser
+        #[arg(long, allow_hyphen_values = true)]
+        offset: Option<Millis>,
+    }
+
+    fn parse_args(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from(std::iter::once("app").chain(args.iter().copied()))
+    }
+
+    // ============================================================================
+    // Test parsing values
+    // ============================================================================
+
+    mod test_parse_values {
+        use super::*;
+
+        #[test]
+        fn should_parse_each_type() {
+            // should produce Duration, i64 and Millis
+            let cli =
+                parse_args(&["--timeout", "1.5s", "--delay", "2m", "--offset", "-1h"]).unwrap();
+            assert_eq!(cli.timeout, Some(Duration::from_millis(1500)));
+            assert_eq!(cli.delay, Some(120000));
+            assert_eq!(cli.offset, Some(Millis(-3600000)));
+        }
+
+        #[test]
+        fn should_accept_bare_numbers_and_spaces() {
+            // should read numbers as milliseconds and allow spaces
+            let cli = parse_args(&["--timeout", "250", "--delay", "1 day"]).unwrap();
+            assert_eq!(cli.timeout, Some(Duration::from_millis(250)));
+            assert_eq!(cli.delay, Some(86400000));
+        }
+
+        #[test]
+        fn should_allow_negative_i64() {
+            // should keep negative values for i64
+            let cli = parse_args(&["--delay", "-5s"]).unwrap();
+            assert_eq!(cli.delay, Some(-5000));
+        }
+    }
+
+    // ============================================================================
+    // Test errors
+    // ============================================================================
+
+    mod test_errors {
+        use super::*;
+
+        #[test]
+        fn should_explain_invalid_values() {
+            // should name the argument and suggest a format
+            let err = parse_args(&["--timeout", "soon"]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ValueValidation);
+            let message = err.to_string();
+            assert!(message.contains("invalid value 'soon' for '--timeout <TIMEOUT>'"));
+            assert!(
+                message.contains("tip: use a number with a unit, such as 500ms, 30s, 5m or 1h\n")
+            );
+        }
+
+        #[test]
+        fn should_suggest_values_within_bounds() {
+            // should only suggest values the parser accepts
+            let parser = DurationParser::<i64>::new().min(60_000);
+            let cmd = clap::Command::new("app")
+                .arg(clap::Arg::new("wait").long("wait").value_parser(parser));
+            let err = cmd
+                .clone()
+                .try_get_matches_from(["app", "--wait", "soon"])
+                .unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("tip: use a number with a unit, such as 5m, 1h or 1d\n")
+            );
+
+            let parser = DurationParser::<i64>::new().min(604_800_000);
+            let cmd = clap::Command::new("app")
+                .arg(clap::Arg::new("wait").long("wait").value_parser(parser));
+            let err = cmd
+                .try_get_matches_from(["app", "--wait", "soon"])
+                .unwrap_err();
+            assert!(err.to_string().contains("tip: use a number with a unit\n"));
+        }
+
+        #[test]
+        fn should_check_bounds() {
+            // should report the bound that was crossed
+            let err = parse_args(&["--timeout", "50ms"]).unwrap_err();
+            assert!(err.to_string().contains("must be at least 100ms"));
+            let err = parse_args(&["--timeout", "2h"]).unwrap_err();
+            assert!(err.to_string().contains("must be at most 1h"));
+            assert!(parse_args(&["--timeout", "1h"]).is_ok());
+            assert!(parse_args(&["--timeout", "100ms"]).is_ok());
+        }
+
+        #[test]
+        fn should_reject_negative_durations() {
+            // should not produce a negative Duration
+            let parser = DurationParser::<Duration>::new();
+            let cmd = clap::Command::new("app").arg(
+                clap::Arg::new("wait")
+                    .long("wait")
+                    .allow_hyphen_values(true)
+                    .value_parser(parser),
+            );
+            let err = cmd
+                .try_get_matches_from(["app", "--wait", "-1s"])
+                .unwrap_err();
+            assert!(err.to_string().contains("duration must not be negative"));
+        }
+    }
+
+    // ============================================================================
+    // Test --help hints
+    // ============================================================================
+
+    mod test_help {
+        use super::*;
+        use clap::CommandFactory;
+
+        #[test]
+        fn should_list_examples_within_bounds() {
+            // should only give examples the parser accepts
+            assert_eq!(timeout_parser().examples(), ["500ms", "30s", "5m", "1h"]);
+            assert_eq!(
+                DurationParser::<i64>::new().examples(),
+                ["500ms", "30s", "5m", "1h", "1d"]
+            );
+            assert!(
+                DurationParser::<i64>::new()
+                    .min(604_800_000)
+                    .examples()
+                    .is_empty()
+            );
+        }
+
+        #[test]
+        fn should_show_examples_as_help_text() {
+            // should not present the examples as a closed set of values
+            let help = Cli::command().render_help().to_string();
+            assert!(
+                help.contains("Request timeout [examples: 500ms, 30s, 5m, 1h]"),
+                "{}",
+                help
+            );
+            assert!(!help.contains("possible values"), "{}", help);
+        }
+
+        #[test]
+        fn should_configure_args() {
+            // should set the parser and add examples to any help text
+            let arg = clap::Arg::new("ttl")
+                .long("ttl")
+                .long_help("Cache lifetime");
+            let arg = DurationParser::<Millis>::new().max(60_000).configure(arg);
+            assert_eq!(
+                arg.get_long_help().unwrap().to_string(),
+                "Cache lifetime [examples: 500ms, 30s]"
+            );
+            assert_eq!(
+                arg.get_help().unwrap().to_string(),
+                "[examples: 500ms, 30s]"
+            );
+
+            let mut cmd = clap::Command::new("app").arg(arg);
+            let matches = cmd
+                .try_get_matches_from_mut(["app", "--ttl", "45s"])
+                .unwrap();
+            assert_eq!(matches.get_one::<Millis>("ttl"), Some(&Millis(45_000)));
+
+            // Without examples within the bounds, the help text is unchanged
+            let arg = clap::Arg::new("ttl").help("Cache lifetime");
+            let arg = DurationParser::<i64>::new().min(604_800_000).configure(arg);
+            assert_eq!(arg.get_help().unwrap().to_string(), "Cache lifetime");
+        }
+
+        #[test]
+        fn should_not_restrict_values() {
+            // should accept values beyond the examples
+            let cli = parse_args(&["--timeout", "42s"]).unwrap();
+            assert_eq!(cli.timeout, Some(Duration::from_secs(42)));
+        }
+    }
+}
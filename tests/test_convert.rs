@@ -0,0 +1,105 @@
+use millis::{Options, Precision, Unit, format, parse_as, to_unit};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============================================================================
+    // Test parse_as(string, unit)
+    // ============================================================================
+
+    mod test_parse_as {
+        use super::*;
+
+        #[test]
+        fn should_convert_to_unit() {
+            // should convert the parsed value to the unit
+            assert_eq!(parse_as("90m", Unit::Hours).unwrap(), 1.5);
+            assert_eq!(parse_as("2h", Unit::Seconds).unwrap(), 7200.0);
+            assert_eq!(parse_as("1d", Unit::Weeks).unwrap(), 1.0 / 7.0);
+            assert_eq!(parse_as("-3s", Unit::Milliseconds).unwrap(), -3000.0);
+            assert_eq!(parse_as("1y", Unit::Months).unwrap(), 12.0);
+        }
+
+        #[test]
+        fn should_not_round_to_milliseconds_first() {
+            // should not round to whole milliseconds first
+            assert_eq!(parse_as("1500µs", Unit::Milliseconds).unwrap(), 1.5);
+            assert_eq!(parse_as("10µs", Unit::Microseconds).unwrap(), 10.0);
+        }
+
+        #[test]
+        fn should_return_error_if_invalid() {
+            // should return an error for invalid input
+            assert!(parse_as("", Unit::Seconds).is_err());
+            assert!(parse_as("5 xyz", Unit::Seconds).is_err());
+        }
+    }
+
+    // ============================================================================
+    // Test to_unit(number, unit)
+    // ============================================================================
+
+    mod test_to_unit {
+        use super::*;
+
+        #[test]
+        fn should_convert_to_unit() {
+            // should convert milliseconds to the unit
+            assert_eq!(to_unit(5400000, Unit::Hours), 1.5);
+            assert_eq!(to_unit(5400000, Unit::Seconds), 5400.0);
+            assert_eq!(to_unit(31557600000, Unit::Days), 365.25);
+            assert_eq!(to_unit(-1209600000, Unit::Fortnights), -1.0);
+            assert_eq!(to_unit(0, Unit::Years), 0.0);
+        }
+    }
+
+    // ============================================================================
+    // Test format(number, unit)
+    // ============================================================================
+
+    mod test_format_unit {
+        use super::*;
+
+        fn forced(unit: Unit, long: bool) -> Option<Options> {
+            Some(
+                Options::new()
+                    .unit(unit)
+                    .long(long)
+                    .precision(Precision::Decimals(2))
+                    .trim_zeros(true),
+            )
+        }
+
+        #[test]
+        fn should_format_in_forced_unit() {
+            // should format in the forced unit
+            assert_eq!(format(5400000, forced(Unit::Seconds, false)), "5400s");
+            assert_eq!(format(5400000, forced(Unit::Hours, true)), "1.5 hours");
+            assert_eq!(format(500, forced(Unit::Seconds, false)), "0.5s");
+            assert_eq!(
+                format(2 * 86400000, forced(Unit::Weeks, true)),
+                "0.29 weeks"
+            );
+        }
+
+        #[test]
+        fn should_round_without_precision() {
+            // should round to whole units without a precision
+            let opts = Some(Options::new().unit(Unit::Hours));
+            assert_eq!(format(5400000, opts), "2h");
+            assert_eq!(format(60000, opts), "0h");
+        }
+
+        #[test]
+        fn should_take_precedence_over_bounds() {
+            // should take precedence over the unit bounds
+            let opts = Some(
+                Options::new()
+                    .unit(Unit::Minutes)
+                    .largest_unit(Unit::Seconds),
+            );
+            assert_eq!(format(5400000, opts), "90m");
+        }
+    }
+}
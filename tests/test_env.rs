@@ -0,0 +1,149 @@
+use millis::{EnvError, EnvOptions, Unit, env_duration, env_duration_with, env_value_duration};
+use std::ffi::OsString;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Read `value` as the variable `name` would be read
+    fn read(name: &str, value: &str, options: Option<EnvOptions>) -> Result<i64, EnvError> {
+        env_value_duration(name, Some(OsString::from(value)), 0, options)
+    }
+
+    // ============================================================================
+    // Test env_duration and env_duration_with
+    // ============================================================================
+
+    mod test_env_duration {
+        use super::*;
+
+        // The environment is shared by every thread, so this is the only test
+        // that sets variables or reads them.
+        #[test]
+        fn should_read_the_environment() {
+            // should read set variables and fall back for unset ones
+            // SAFETY: no other test in this binary reads or writes the environment
+            unsafe { std::env::set_var("MILLIS_TEST_TIMEOUT", "30s") };
+            assert_eq!(env_duration("MILLIS_TEST_TIMEOUT", 1000).unwrap(), 30000);
+            assert_eq!(env_duration("MILLIS_TEST_UNSET", 1234).unwrap(), 1234);
+
+            let options = EnvOptions::new().max(10000);
+            let err = env_duration_with("MILLIS_TEST_TIMEOUT", 0, Some(options)).unwrap_err();
+            assert_eq!(err.name(), "MILLIS_TEST_TIMEOUT");
+            assert!(matches!(err, EnvError::TooLong { max: 10000, .. }));
+        }
+    }
+
+    // ============================================================================
+    // Test env_value_duration
+    // ============================================================================
+
+    mod test_env_value_duration {
+        use super::*;
+
+        #[test]
+        fn should_parse_values() {
+            // should parse the value like parse()
+            assert_eq!(read("TIMEOUT", "30s", None).unwrap(), 30000);
+            assert_eq!(read("TIMEOUT", "  1h 30m ", None).unwrap(), 5400000);
+            assert_eq!(read("TIMEOUT", "250", None).unwrap(), 250);
+        }
+
+        #[test]
+        fn should_use_default() {
+            // should fall back when unset or empty
+            assert_eq!(
+                env_value_duration("TIMEOUT", None, 1234, None).unwrap(),
+                1234
+            );
+            assert_eq!(
+                env_value_duration("TIMEOUT", Some(" ".into()), 1234, None).unwrap(),
+                1234
+            );
+        }
+
+        #[test]
+        fn should_name_the_variable() {
+            // should report invalid values with the variable name
+            let err = read("TIMEOUT", "soon", None).unwrap_err();
+            assert_eq!(err.name(), "TIMEOUT");
+            assert!(matches!(&err, EnvError::Invalid { value, .. } if value == "soon"));
+            assert!(
+                err.to_string()
+                    .starts_with("Environment variable TIMEOUT is not a duration")
+            );
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn should_report_non_unicode() {
+            // should report values that are not Unicode
+            use std::os::unix::ffi::OsStringExt;
+
+            let value = OsString::from_vec(b"\xff".to_vec());
+            let err = env_value_duration("TIMEOUT", Some(value), 0, None).unwrap_err();
+            assert_eq!(
+                err,
+                EnvError::NotUnicode {
+                    name: "TIMEOUT".to_string()
+                }
+            );
+        }
+
+        #[test]
+        fn should_check_bounds() {
+            // should reject values outside the bounds
+            let options = EnvOptions::new().min(1000).max(60000);
+            let err = read("TIMEOUT", "500ms", Some(options)).unwrap_err();
+            assert!(matches!(err, EnvError::TooShort { min: 1000, .. }));
+            assert_eq!(
+                err.to_string(),
+                "Environment variable TIMEOUT must be at least 1s. value=\"500ms\""
+            );
+
+            let err = read("TIMEOUT", "2m", Some(options)).unwrap_err();
+            assert!(matches!(err, EnvError::TooLong { max: 60000, .. }));
+
+            assert_eq!(read("TIMEOUT", "1m", Some(options)).unwrap(), 60000);
+        }
+
+        #[test]
+        fn should_not_check_default() {
+            // should return the default as given
+            let options = EnvOptions::new().min(1000);
+            assert_eq!(
+                env_value_duration("TIMEOUT", None, 0, Some(options)).unwrap(),
+                0
+            );
+        }
+
+        #[test]
+        fn should_use_bare_unit() {
+            // should read bare numbers in the given unit
+            let options = EnvOptions::new().bare_unit(Unit::Seconds);
+            assert_eq!(read("TIMEOUT", "30", Some(options)).unwrap(), 30000);
+            assert_eq!(read("TIMEOUT", "-1.5", Some(options)).unwrap(), -1500);
+            assert_eq!(read("TIMEOUT", "250ms", Some(options)).unwrap(), 250);
+
+            let minutes = EnvOptions::new().bare_unit(Unit::Minutes);
+            assert_eq!(read("TIMEOUT", "2", Some(minutes)).unwrap(), 120000);
+        }
+
+        #[test]
+        fn should_read_bare_numbers_directly() {
+            // should not be limited by or quote a rewritten time string
+            let options = EnvOptions::new().bare_unit(Unit::Seconds);
+            let long = format!("30.{}", "0".repeat(120));
+            assert_eq!(read("TIMEOUT", &long, Some(options)).unwrap(), 30000);
+
+            let err = read("TIMEOUT", "99999999999999999999", Some(options)).unwrap_err();
+            assert!(matches!(
+                &err,
+                EnvError::Invalid { reason, .. }
+                    if reason == "Duration out of range. value=\"99999999999999999999\""
+            ));
+
+            assert!(read("TIMEOUT", "1.2.3", Some(options)).is_err());
+        }
+    }
+}
@@ -1,3 +1,6 @@
+// Spelled-out unit arithmetic (e.g. `-1 * 60 * 1000`) mirrors the upstream ms test suite.
+#![allow(clippy::identity_op, clippy::neg_multiply)]
+
 use millis::{Options, format};
 
 #[cfg(test)]
@@ -14,48 +17,63 @@ mod tests {
         #[test]
         fn should_not_throw_an_error() {
             // should not throw an error
-            let _ = format(500, Some(Options { long: true }));
+            let _ = format(500, Some(Options::new().long(true)));
         }
 
         #[test]
         fn should_support_milliseconds() {
             // should support milliseconds
-            assert_eq!(format(500, Some(Options { long: true })), "500 ms");
-            assert_eq!(format(-500, Some(Options { long: true })), "-500 ms");
+            assert_eq!(
+                format(500, Some(Options::new().long(true))),
+                "500 milliseconds"
+            );
+            assert_eq!(
+                format(-500, Some(Options::new().long(true))),
+                "-500 milliseconds"
+            );
         }
 
         #[test]
         fn should_support_seconds() {
             // should support seconds
-            assert_eq!(format(1000, Some(Options { long: true })), "1 second");
-            assert_eq!(format(1200, Some(Options { long: true })), "1 second");
-            assert_eq!(format(10000, Some(Options { long: true })), "10 seconds");
+            assert_eq!(format(1000, Some(Options::new().long(true))), "1 second");
+            assert_eq!(format(1200, Some(Options::new().long(true))), "1 second");
+            assert_eq!(format(10000, Some(Options::new().long(true))), "10 seconds");
 
-            assert_eq!(format(-1000, Some(Options { long: true })), "-1 second");
-            assert_eq!(format(-1200, Some(Options { long: true })), "-1 second");
-            assert_eq!(format(-10000, Some(Options { long: true })), "-10 seconds");
+            assert_eq!(format(-1000, Some(Options::new().long(true))), "-1 second");
+            assert_eq!(format(-1200, Some(Options::new().long(true))), "-1 second");
+            assert_eq!(
+                format(-10000, Some(Options::new().long(true))),
+                "-10 seconds"
+            );
         }
 
         #[test]
         fn should_support_minutes() {
             // should support minutes
-            assert_eq!(format(60 * 1000, Some(Options { long: true })), "1 minute");
-            assert_eq!(format(60 * 1200, Some(Options { long: true })), "1 minute");
             assert_eq!(
-                format(60 * 10000, Some(Options { long: true })),
+                format(60 * 1000, Some(Options::new().long(true))),
+                "1 minute"
+            );
+            assert_eq!(
+                format(60 * 1200, Some(Options::new().long(true))),
+                "1 minute"
+            );
+            assert_eq!(
+                format(60 * 10000, Some(Options::new().long(true))),
                 "10 minutes"
             );
 
             assert_eq!(
-                format(-1 * 60 * 1000, Some(Options { long: true })),
+                format(-1 * 60 * 1000, Some(Options::new().long(true))),
                 "-1 minute"
             );
             assert_eq!(
-                format(-1 * 60 * 1200, Some(Options { long: true })),
+                format(-1 * 60 * 1200, Some(Options::new().long(true))),
                 "-1 minute"
             );
             assert_eq!(
-                format(-1 * 60 * 10000, Some(Options { long: true })),
+                format(-1 * 60 * 10000, Some(Options::new().long(true))),
                 "-10 minutes"
             );
         }
@@ -64,28 +82,28 @@ mod tests {
         fn should_support_hours() {
             // should support hours
             assert_eq!(
-                format(60 * 60 * 1000, Some(Options { long: true })),
+                format(60 * 60 * 1000, Some(Options::new().long(true))),
                 "1 hour"
             );
             assert_eq!(
-                format(60 * 60 * 1200, Some(Options { long: true })),
+                format(60 * 60 * 1200, Some(Options::new().long(true))),
                 "1 hour"
             );
             assert_eq!(
-                format(60 * 60 * 10000, Some(Options { long: true })),
+                format(60 * 60 * 10000, Some(Options::new().long(true))),
                 "10 hours"
             );
 
             assert_eq!(
-                format(-1 * 60 * 60 * 1000, Some(Options { long: true })),
+                format(-1 * 60 * 60 * 1000, Some(Options::new().long(true))),
                 "-1 hour"
             );
             assert_eq!(
-                format(-1 * 60 * 60 * 1200, Some(Options { long: true })),
+                format(-1 * 60 * 60 * 1200, Some(Options::new().long(true))),
                 "-1 hour"
             );
             assert_eq!(
-                format(-1 * 60 * 60 * 10000, Some(Options { long: true })),
+                format(-1 * 60 * 60 * 10000, Some(Options::new().long(true))),
                 "-10 hours"
             );
         }
@@ -94,28 +112,37 @@ mod tests {
         fn should_support_days() {
             // should support days
             assert_eq!(
-                format(1 * 24 * 60 * 60 * 1000, Some(Options { long: true })),
+                format(1 * 24 * 60 * 60 * 1000, Some(Options::new().long(true))),
                 "1 day"
             );
             assert_eq!(
-                format(1 * 24 * 60 * 60 * 1200, Some(Options { long: true })),
+                format(1 * 24 * 60 * 60 * 1200, Some(Options::new().long(true))),
                 "1 day"
             );
             assert_eq!(
-                format(6 * 24 * 60 * 60 * 1000, Some(Options { long: true })),
+                format(6 * 24 * 60 * 60 * 1000, Some(Options::new().long(true))),
                 "6 days"
             );
 
             assert_eq!(
-                format(-1 * 1 * 24 * 60 * 60 * 1000, Some(Options { long: true })),
+                format(
+                    -1 * 1 * 24 * 60 * 60 * 1000,
+                    Some(Options::new().long(true))
+                ),
                 "-1 day"
             );
             assert_eq!(
-                format(-1 * 1 * 24 * 60 * 60 * 1200, Some(Options { long: true })),
+                format(
+                    -1 * 1 * 24 * 60 * 60 * 1200,
+                    Some(Options::new().long(true))
+                ),
                 "-1 day"
             );
             assert_eq!(
-                format(-1 * 6 * 24 * 60 * 60 * 1000, Some(Options { long: true })),
+                format(
+                    -1 * 6 * 24 * 60 * 60 * 1000,
+                    Some(Options::new().long(true))
+                ),
                 "-6 days"
             );
         }
@@ -124,25 +151,25 @@ mod tests {
         fn should_support_weeks() {
             // should support weeks
             assert_eq!(
-                format(1 * 7 * 24 * 60 * 60 * 1000, Some(Options { long: true })),
+                format(1 * 7 * 24 * 60 * 60 * 1000, Some(Options::new().long(true))),
                 "1 week"
             );
             assert_eq!(
-                format(2 * 7 * 24 * 60 * 60 * 1000, Some(Options { long: true })),
+                format(2 * 7 * 24 * 60 * 60 * 1000, Some(Options::new().long(true))),
                 "2 weeks"
             );
 
             assert_eq!(
                 format(
                     -1 * 1 * 7 * 24 * 60 * 60 * 1000,
-                    Some(Options { long: true })
+                    Some(Options::new().long(true))
                 ),
                 "-1 week"
             );
             assert_eq!(
                 format(
                     -1 * 2 * 7 * 24 * 60 * 60 * 1000,
-                    Some(Options { long: true })
+                    Some(Options::new().long(true))
                 ),
                 "-2 weeks"
             );
@@ -152,34 +179,40 @@ mod tests {
         fn should_support_months() {
             // should support months
             let one_month = (30.4375 * 24.0 * 60.0 * 60.0 * 1000.0) as i64;
-            assert_eq!(format(one_month, Some(Options { long: true })), "1 month");
+            assert_eq!(
+                format(one_month, Some(Options::new().long(true))),
+                "1 month"
+            );
             assert_eq!(
                 format(
                     (30.4375 * 24.0 * 60.0 * 60.0 * 1200.0) as i64,
-                    Some(Options { long: true })
+                    Some(Options::new().long(true))
                 ),
                 "1 month"
             );
             assert_eq!(
                 format(
                     (30.4375 * 24.0 * 60.0 * 60.0 * 10000.0) as i64,
-                    Some(Options { long: true })
+                    Some(Options::new().long(true))
                 ),
                 "10 months"
             );
 
-            assert_eq!(format(-one_month, Some(Options { long: true })), "-1 month");
+            assert_eq!(
+                format(-one_month, Some(Options::new().long(true))),
+                "-1 month"
+            );
             assert_eq!(
                 format(
                     -(30.4375 * 24.0 * 60.0 * 60.0 * 1200.0) as i64,
-                    Some(Options { long: true })
+                    Some(Options::new().long(true))
                 ),
                 "-1 month"
             );
             assert_eq!(
                 format(
                     -(30.4375 * 24.0 * 60.0 * 60.0 * 10000.0) as i64,
-                    Some(Options { long: true })
+                    Some(Options::new().long(true))
                 ),
                 "-10 months"
             );
@@ -189,34 +222,37 @@ mod tests {
         fn should_support_years() {
             // should support years
             let one_year = (365.25 * 24.0 * 60.0 * 60.0 * 1000.0) as i64 + 1;
-            assert_eq!(format(one_year, Some(Options { long: true })), "1 year");
+            assert_eq!(format(one_year, Some(Options::new().long(true))), "1 year");
             assert_eq!(
                 format(
                     (365.25 * 24.0 * 60.0 * 60.0 * 1200.0) as i64 + 1,
-                    Some(Options { long: true })
+                    Some(Options::new().long(true))
                 ),
                 "1 year"
             );
             assert_eq!(
                 format(
                     (365.25 * 24.0 * 60.0 * 60.0 * 10000.0) as i64 + 1,
-                    Some(Options { long: true })
+                    Some(Options::new().long(true))
                 ),
                 "10 years"
             );
 
-            assert_eq!(format(-one_year, Some(Options { long: true })), "-1 year");
+            assert_eq!(
+                format(-one_year, Some(Options::new().long(true))),
+                "-1 year"
+            );
             assert_eq!(
                 format(
                     -((365.25 * 24.0 * 60.0 * 60.0 * 1200.0) as i64 + 1),
-                    Some(Options { long: true })
+                    Some(Options::new().long(true))
                 ),
                 "-1 year"
             );
             assert_eq!(
                 format(
                     -((365.25 * 24.0 * 60.0 * 60.0 * 10000.0) as i64 + 1),
-                    Some(Options { long: true })
+                    Some(Options::new().long(true))
                 ),
                 "-10 years"
             );
@@ -225,8 +261,11 @@ mod tests {
         #[test]
         fn should_round() {
             // should round
-            assert_eq!(format(234234234, Some(Options { long: true })), "3 days");
-            assert_eq!(format(-234234234, Some(Options { long: true })), "-3 days");
+            assert_eq!(format(234234234, Some(Options::new().long(true))), "3 days");
+            assert_eq!(
+                format(-234234234, Some(Options::new().long(true))),
+                "-3 days"
+            );
         }
     }
 
@@ -357,4 +396,96 @@ mod tests {
             assert_eq!(format(-234234234, None), "-3d");
         }
     }
+    // ============================================================================
+    // Test format(number, case_sensitive / m_policy)
+    // ============================================================================
+
+    mod test_format_unit_names {
+        use super::*;
+        use millis::MPolicy;
+
+        #[test]
+        fn should_write_months_as_uppercase_m_when_case_sensitive() {
+            // should write months as "M" when case-sensitive
+            let opts = Options::new().case_sensitive(true);
+            assert_eq!(format(3 * 2629800000, Some(opts)), "3M");
+            assert_eq!(format(-3 * 2629800000, Some(opts)), "-3M");
+            assert_eq!(format(5 * 60000, Some(opts)), "5m");
+        }
+
+        #[test]
+        fn should_avoid_single_letter_m_when_rejected() {
+            // should write "min" and "mo" when "m" is rejected
+            let opts = Options::new()
+                .case_sensitive(true)
+                .m_policy(MPolicy::Reject);
+            assert_eq!(format(5 * 60000, Some(opts)), "5min");
+            assert_eq!(format(3 * 2629800000, Some(opts)), "3mo");
+        }
+
+        #[test]
+        fn should_not_change_long_format() {
+            // should not change long format
+            let opts = Options::new()
+                .long(true)
+                .case_sensitive(true)
+                .m_policy(MPolicy::Reject);
+            assert_eq!(format(5 * 60000, Some(opts)), "5 minutes");
+            assert_eq!(format(3 * 2629800000, Some(opts)), "3 months");
+        }
+    }
+    // ============================================================================
+    // Test format(number, extended_units)
+    // ============================================================================
+
+    mod test_format_extended_units {
+        use super::*;
+
+        const YEAR: i64 = 31557600000;
+
+        fn extended(long: bool) -> Option<Options> {
+            Some(Options::new().long(long).extended_units(true))
+        }
+
+        #[test]
+        fn should_not_use_extended_units_by_default() {
+            // should keep years as the largest unit by default
+            assert_eq!(format(300 * YEAR, None), "300y");
+            assert_eq!(
+                format(300 * YEAR, Some(Options::new().long(true))),
+                "300 years"
+            );
+        }
+
+        #[test]
+        fn should_support_decades() {
+            // should support decades
+            assert_eq!(format(10 * YEAR, extended(false)), "1 decade");
+            assert_eq!(format(20 * YEAR, extended(true)), "2 decades");
+            assert_eq!(format(-10 * YEAR, extended(true)), "-1 decade");
+        }
+
+        #[test]
+        fn should_support_centuries() {
+            // should support centuries
+            assert_eq!(format(300 * YEAR, extended(false)), "3 centuries");
+            assert_eq!(format(100 * YEAR, extended(true)), "1 century");
+            assert_eq!(format(300 * YEAR, extended(true)), "3 centuries");
+        }
+
+        #[test]
+        fn should_support_millennia() {
+            // should support millennia
+            assert_eq!(format(2000 * YEAR, extended(false)), "2 millennia");
+            assert_eq!(format(1000 * YEAR, extended(true)), "1 millennium");
+            assert_eq!(format(2000 * YEAR, extended(true)), "2 millennia");
+        }
+
+        #[test]
+        fn should_not_change_smaller_units() {
+            // should not change values below a decade
+            assert_eq!(format(9 * YEAR, extended(false)), "9y");
+            assert_eq!(format(3600000, extended(true)), "1 hour");
+        }
+    }
 }
@@ -0,0 +1,153 @@
+use millis::{ApproxOptions, Unit, format_approx};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: i64 = 1000;
+    const MINUTE: i64 = 60 * SECOND;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    const WEEK: i64 = 7 * DAY;
+    const MONTH: i64 = 2629800000;
+    const YEAR: i64 = 31557600000;
+
+    // ============================================================================
+    // Test format_approx(number)
+    // ============================================================================
+
+    mod test_format_approx {
+        use super::*;
+
+        #[test]
+        fn should_say_less_than_a_minute() {
+            // should say "less than a minute" below one minute
+            assert_eq!(format_approx(0, None), "less than a minute");
+            assert_eq!(format_approx(59 * SECOND, None), "less than a minute");
+        }
+
+        #[test]
+        fn should_say_about() {
+            // should say "about" just above a whole unit
+            assert_eq!(format_approx(MINUTE, None), "about 1 minute");
+            assert_eq!(format_approx(HOUR + 10 * MINUTE, None), "about 1 hour");
+            assert_eq!(format_approx(3 * DAY, None), "about 3 days");
+            assert_eq!(format_approx(2 * MONTH, None), "about 2 months");
+        }
+
+        #[test]
+        fn should_say_over() {
+            // should say "over" between the thresholds
+            assert_eq!(format_approx(3 * DAY + 12 * HOUR, None), "over 3 days");
+            assert_eq!(format_approx(YEAR + YEAR / 4, None), "over 1 year");
+        }
+
+        #[test]
+        fn should_say_almost() {
+            // should say "almost" near the next whole unit
+            assert_eq!(format_approx(YEAR + YEAR * 4 / 5, None), "almost 2 years");
+            assert_eq!(
+                format_approx(2 * HOUR + 50 * MINUTE, None),
+                "almost 3 hours"
+            );
+        }
+
+        #[test]
+        fn should_move_to_the_next_unit() {
+            // should name the next unit rather than "almost 60 minutes"
+            assert_eq!(
+                format_approx(59 * MINUTE + 50 * SECOND, None),
+                "almost 1 hour"
+            );
+            assert_eq!(format_approx(23 * HOUR + 50 * MINUTE, None), "almost 1 day");
+            assert_eq!(format_approx(11 * MONTH + 25 * DAY, None), "almost 1 year");
+        }
+
+        #[test]
+        fn should_ignore_the_sign() {
+            // should ignore the sign
+            assert_eq!(format_approx(-3 * DAY, None), "about 3 days");
+            assert_eq!(format_approx(i64::MIN, None), "about 292271023 years");
+        }
+    }
+
+    // ============================================================================
+    // Test format_approx(number, options)
+    // ============================================================================
+
+    mod test_format_approx_options {
+        use super::*;
+
+        #[test]
+        fn should_use_custom_thresholds() {
+            // should use custom thresholds
+            let opts = ApproxOptions::new().over(0.1).almost(0.9);
+            assert_eq!(format_approx(HOUR + 10 * MINUTE, Some(opts)), "over 1 hour");
+            assert_eq!(format_approx(HOUR + 50 * MINUTE, Some(opts)), "over 1 hour");
+            assert_eq!(
+                format_approx(HOUR + 55 * MINUTE, Some(opts)),
+                "almost 2 hours"
+            );
+        }
+
+        #[test]
+        fn should_use_smallest_unit() {
+            // should go down to the smallest unit
+            let seconds = ApproxOptions::new().smallest_unit(Unit::Seconds);
+            assert_eq!(format_approx(500, Some(seconds)), "less than a second");
+            assert_eq!(
+                format_approx(30 * SECOND, Some(seconds)),
+                "about 30 seconds"
+            );
+
+            let hours = ApproxOptions::new().smallest_unit(Unit::Hours);
+            assert_eq!(format_approx(30 * MINUTE, Some(hours)), "less than an hour");
+        }
+
+        #[test]
+        fn should_use_weeks_as_smallest_unit() {
+            // should count in weeks and fortnights when asked for
+            let weeks = ApproxOptions::new().smallest_unit(Unit::Weeks);
+            assert_eq!(format_approx(14 * DAY, Some(weeks)), "about 2 weeks");
+            assert_eq!(format_approx(3 * DAY, Some(weeks)), "less than a week");
+            assert_eq!(format_approx(2 * MONTH, Some(weeks)), "about 2 months");
+
+            let fortnights = ApproxOptions::new().smallest_unit(Unit::Fortnights);
+            assert_eq!(
+                format_approx(4 * WEEK, Some(fortnights)),
+                "about 2 fortnights"
+            );
+            assert_eq!(
+                format_approx(WEEK, Some(fortnights)),
+                "less than a fortnight"
+            );
+        }
+
+        #[test]
+        fn should_use_extended_units_as_smallest_unit() {
+            // should count in decades, centuries and millennia when asked for
+            let decades = ApproxOptions::new().smallest_unit(Unit::Decades);
+            assert_eq!(
+                format_approx(1000 * YEAR, Some(decades)),
+                "about 100 decades"
+            );
+            assert_eq!(format_approx(5 * YEAR, Some(decades)), "less than a decade");
+
+            let centuries = ApproxOptions::new().smallest_unit(Unit::Centuries);
+            assert_eq!(
+                format_approx(250 * YEAR, Some(centuries)),
+                "over 2 centuries"
+            );
+
+            let millennia = ApproxOptions::new().smallest_unit(Unit::Millennia);
+            assert_eq!(
+                format_approx(2000 * YEAR, Some(millennia)),
+                "about 2 millennia"
+            );
+            assert_eq!(
+                format_approx(500 * YEAR, Some(millennia)),
+                "less than a millennium"
+            );
+        }
+    }
+}
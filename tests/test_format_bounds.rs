@@ -0,0 +1,138 @@
+use millis::{Options, Precision, Unit, format};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86400000;
+
+    fn bounded(largest: Option<Unit>, smallest: Option<Unit>) -> Option<Options> {
+        let mut opts = Options::new();
+        opts.largest_unit = largest;
+        opts.smallest_unit = smallest;
+        Some(opts)
+    }
+
+    // ============================================================================
+    // Test format(number, largest_unit)
+    // ============================================================================
+
+    mod test_format_largest_unit {
+        use super::*;
+
+        #[test]
+        fn should_never_go_above_largest_unit() {
+            // should never go above the largest unit
+            let opts = bounded(Some(Unit::Hours), None);
+            assert_eq!(format(36 * 3600000, opts), "36h");
+            assert_eq!(format(2 * 365 * DAY, opts), "17520h");
+            assert_eq!(format(-2 * DAY, opts), "-48h");
+        }
+
+        #[test]
+        fn should_still_use_smaller_units() {
+            // should still use smaller units for smaller values
+            let opts = bounded(Some(Unit::Hours), None);
+            assert_eq!(format(90000, opts), "2m");
+            assert_eq!(format(500, opts), "500ms");
+        }
+
+        #[test]
+        fn should_allow_extended_units() {
+            // should allow extended units up to the largest unit
+            let opts = bounded(Some(Unit::Centuries), None);
+            assert_eq!(format(300 * 31557600000, opts), "3 centuries");
+            assert_eq!(format(3000 * 31557600000, opts), "30 centuries");
+        }
+
+        #[test]
+        fn should_use_fortnights_only_when_asked() {
+            // should use fortnights only when they are a bound
+            assert_eq!(format(28 * DAY, None), "4w");
+            assert_eq!(
+                format(28 * DAY, bounded(Some(Unit::Fortnights), None)),
+                "2 fortnights"
+            );
+        }
+    }
+
+    // ============================================================================
+    // Test format(number, smallest_unit)
+    // ============================================================================
+
+    mod test_format_smallest_unit {
+        use super::*;
+
+        #[test]
+        fn should_never_go_below_smallest_unit() {
+            // should round values below the smallest unit to it
+            let opts = bounded(None, Some(Unit::Seconds));
+            assert_eq!(format(400, opts), "0s");
+            assert_eq!(format(500, opts), "1s");
+            assert_eq!(format(-500, opts), "-1s");
+            assert_eq!(format(-400, opts), "0s");
+            assert_eq!(format(0, opts), "0s");
+        }
+
+        #[test]
+        fn should_still_use_larger_units() {
+            // should still use larger units for larger values
+            let opts = bounded(None, Some(Unit::Seconds));
+            assert_eq!(format(3600000, opts), "1h");
+        }
+
+        #[test]
+        fn should_support_microseconds() {
+            // should support microseconds as the smallest unit
+            let opts = bounded(Some(Unit::Microseconds), Some(Unit::Microseconds));
+            assert_eq!(format(5, opts), "5000µs");
+        }
+
+        #[test]
+        fn should_support_fractional_values() {
+            // should show fractions of the smallest unit with a precision
+            let opts = Some(
+                Options::new()
+                    .smallest_unit(Unit::Seconds)
+                    .precision(Precision::Decimals(2)),
+            );
+            assert_eq!(format(250, opts), "0.25s");
+        }
+    }
+
+    // ============================================================================
+    // Test format(number, largest_unit, smallest_unit)
+    // ============================================================================
+
+    mod test_format_unit_range {
+        use super::*;
+
+        #[test]
+        fn should_choose_within_range() {
+            // should choose a unit between ms and s for latencies
+            let opts = bounded(Some(Unit::Seconds), Some(Unit::Milliseconds));
+            assert_eq!(format(250, opts), "250ms");
+            assert_eq!(format(2500, opts), "3s");
+            assert_eq!(format(3600000, opts), "3600s");
+        }
+
+        #[test]
+        fn should_accept_bounds_in_either_order() {
+            // should accept the bounds in either order
+            assert_eq!(
+                format(
+                    3600000,
+                    bounded(Some(Unit::Milliseconds), Some(Unit::Seconds))
+                ),
+                "3600s"
+            );
+        }
+
+        #[test]
+        fn should_apply_to_long_format() {
+            // should apply to long format
+            let opts = Some(Options::new().long(true).largest_unit(Unit::Hours));
+            assert_eq!(format(3 * DAY, opts), "72 hours");
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use millis::{format_exact, parse};
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: i64 = 1000;
+    const MINUTE: i64 = 60 * SECOND;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    const WEEK: i64 = 7 * DAY;
+    const MONTH: i64 = 2629800000;
+    const YEAR: i64 = 31557600000;
+
+    // ============================================================================
+    // Test parse(compound string)
+    // ============================================================================
+
+    mod test_parse_compound {
+        use super::*;
+
+        #[test]
+        fn should_add_up_parts() {
+            // should add up several numbers with units
+            assert_eq!(parse("1h30m").unwrap(), HOUR + 30 * MINUTE);
+            assert_eq!(parse("1d 12h").unwrap(), DAY + 12 * HOUR);
+            assert_eq!(parse("2 hours 5 minutes").unwrap(), 2 * HOUR + 5 * MINUTE);
+            assert_eq!(parse("1y2mo3w").unwrap(), YEAR + 2 * MONTH + 3 * WEEK);
+            assert_eq!(parse("1m30s500ms").unwrap(), 90500);
+            assert_eq!(parse("1.5h30m").unwrap(), 2 * HOUR);
+        }
+
+        #[test]
+        fn should_apply_sign_to_whole_string() {
+            // should apply a leading sign to every part
+            assert_eq!(parse("-1h30m").unwrap(), -(HOUR + 30 * MINUTE));
+            assert_eq!(parse("−1d1s").unwrap(), -(DAY + SECOND));
+        }
+
+        #[test]
+        fn should_split_months_from_minutes() {
+            // should read "mo" as months inside a compound string
+            assert_eq!(parse("1mo30d").unwrap(), MONTH + 30 * DAY);
+            assert_eq!(parse("1m30d").unwrap(), MINUTE + 30 * DAY);
+        }
+
+        #[test]
+        fn should_return_error_if_invalid() {
+            // should return an error for invalid compound strings
+            assert!(parse("1h-30m").is_err());
+            assert!(parse("1h 30").is_err());
+            assert!(parse("1h xyz").is_err());
+            assert!(parse("1h 5 xyz").is_err());
+        }
+    }
+
+    // ============================================================================
+    // Test parse(large integer)
+    // ============================================================================
+
+    mod test_parse_exact_integers {
+        use super::*;
+
+        #[test]
+        fn should_not_round_through_f64() {
+            // should parse integers beyond 2^53 exactly
+            assert_eq!(parse("9007199254740993").unwrap(), 9007199254740993);
+            assert_eq!(parse("9007199254740993ms").unwrap(), 9007199254740993);
+            assert_eq!(parse("9223372036854775807").unwrap(), i64::MAX);
+            assert_eq!(parse("-9223372036854775808").unwrap(), i64::MIN);
+            assert_eq!(parse("292271023y").unwrap(), 292271023 * YEAR);
+        }
+    }
+
+    // ============================================================================
+    // Test format_exact(number)
+    // ============================================================================
+
+    mod test_format_exact {
+        use super::*;
+
+        #[test]
+        fn should_use_a_single_unit_when_exact() {
+            // should use a single unit when it is exact and shortest
+            assert_eq!(format_exact(0), "0");
+            assert_eq!(format_exact(SECOND), "1s");
+            assert_eq!(format_exact(90 * MINUTE), "90m");
+            assert_eq!(format_exact(36 * HOUR), "36h");
+            assert_eq!(format_exact(2 * WEEK), "2w");
+            assert_eq!(format_exact(13 * MONTH), "13mo");
+            assert_eq!(format_exact(-YEAR), "-1y");
+        }
+
+        #[test]
+        fn should_combine_units() {
+            // should combine units when that is shorter
+            assert_eq!(format_exact(DAY + MINUTE), "1d1m");
+            assert_eq!(format_exact(YEAR + DAY), "1y1d");
+            assert_eq!(format_exact(-(WEEK + SECOND)), "-1w1s");
+        }
+
+        #[test]
+        fn should_fall_back_to_milliseconds() {
+            // should write a plain number when that is shortest
+            assert_eq!(format_exact(1234), "1234");
+            assert_eq!(format_exact(-90061001), "-90061001");
+            assert_eq!(format_exact(i64::MAX), "9223372036854775807");
+        }
+
+        #[test]
+        fn should_round_trip_extremes() {
+            // should round-trip the ends of the i64 range
+            for x in [i64::MIN, i64::MIN + 1, i64::MAX, -1, 0, 1] {
+                assert_eq!(parse(&format_exact(x)).unwrap(), x);
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn should_round_trip_any_i64(x in any::<i64>()) {
+                // should parse back to the same value
+                prop_assert_eq!(parse(&format_exact(x)).unwrap(), x);
+            }
+
+            #[test]
+            fn should_round_trip_whole_units(
+                n in -1_000_000i64..1_000_000,
+                unit in prop::sample::select(vec![SECOND, MINUTE, HOUR, DAY, WEEK, MONTH, YEAR]),
+                rest in 0i64..1000,
+            ) {
+                // should parse back values made of whole units
+                let x = n * unit + rest;
+                let text = format_exact(x);
+                prop_assert_eq!(parse(&text).unwrap(), x);
+                prop_assert!(text.len() <= x.to_string().len());
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use millis::{Options, Precision, RoundingMode, Style, Unit, format};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: i64 = 1000;
+    const MINUTE: i64 = 60 * SECOND;
+    const HOUR: i64 = 60 * MINUTE;
+
+    fn long(opts: Options) -> Option<Options> {
+        Some(opts.long(true))
+    }
+
+    // ============================================================================
+    // Test format(number, long=True) pluralization
+    // ============================================================================
+
+    mod test_format_plural {
+        use super::*;
+
+        #[test]
+        fn should_write_milliseconds_as_words() {
+            // should write milliseconds as words
+            assert_eq!(format(1, long(Options::default())), "1 millisecond");
+            assert_eq!(format(-1, long(Options::default())), "-1 millisecond");
+            assert_eq!(format(0, long(Options::default())), "0 milliseconds");
+            assert_eq!(format(999, long(Options::default())), "999 milliseconds");
+        }
+
+        #[test]
+        fn should_pluralize_the_value_shown() {
+            // should pluralize the value shown, not the underlying value
+            let floor = Options::new().rounding(RoundingMode::Floor);
+            assert_eq!(format(HOUR + 40 * MINUTE, long(floor)), "1 hour");
+            assert_eq!(
+                format(HOUR + 40 * MINUTE, long(Options::default())),
+                "2 hours"
+            );
+            assert_eq!(
+                format(HOUR + 20 * MINUTE, long(Options::default())),
+                "1 hour"
+            );
+        }
+
+        #[test]
+        fn should_pluralize_zero() {
+            // should pluralize zero values
+            let minutes = Options::new().smallest_unit(Unit::Minutes);
+            assert_eq!(format(SECOND, long(minutes)), "0 minutes");
+            assert_eq!(format(-SECOND, long(minutes)), "0 minutes");
+        }
+
+        #[test]
+        fn should_pluralize_fractional_values() {
+            // should pluralize fractional values, including "1.0"
+            let precise = Options::new().precision(Precision::Decimals(1));
+            assert_eq!(format(HOUR, long(precise)), "1.0 hours");
+            assert_eq!(format(HOUR / 2, long(precise)), "30.0 minutes");
+            let trimmed = precise.trim_zeros(true);
+            assert_eq!(format(HOUR, long(trimmed)), "1 hour");
+            assert_eq!(format(-HOUR, long(trimmed)), "-1 hour");
+        }
+
+        #[test]
+        fn should_apply_to_short_style() {
+            // should apply the same rule to the short style
+            let short = Options::new()
+                .style(Style::Short)
+                .rounding(RoundingMode::Floor);
+            assert_eq!(format(HOUR + 40 * MINUTE, Some(short)), "1 hr");
+            assert_eq!(format(500, Some(short)), "500 ms");
+        }
+    }
+
+    // ============================================================================
+    // Test format(number, legacy_plural=True)
+    // ============================================================================
+
+    mod test_format_legacy_plural {
+        use super::*;
+
+        fn legacy() -> Options {
+            Options::new().long(true).legacy_plural(true)
+        }
+
+        #[test]
+        fn should_write_ms() {
+            // should write milliseconds as "ms"
+            assert_eq!(format(500, Some(legacy())), "500 ms");
+            assert_eq!(format(-1, Some(legacy())), "-1 ms");
+        }
+
+        #[test]
+        fn should_pluralize_from_one_and_a_half_units() {
+            // should pluralize from 1.5 units up
+            assert_eq!(format(HOUR + 20 * MINUTE, Some(legacy())), "1 hour");
+            assert_eq!(format(HOUR + 30 * MINUTE, Some(legacy())), "2 hours");
+            let floor = legacy().rounding(RoundingMode::Floor);
+            assert_eq!(format(HOUR + 40 * MINUTE, Some(floor)), "1 hours");
+        }
+    }
+}
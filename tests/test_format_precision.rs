@@ -0,0 +1,143 @@
+use millis::{Options, Precision, format};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn precision(precision: Precision, trim_zeros: bool, long: bool) -> Option<Options> {
+        Some(
+            Options::new()
+                .long(long)
+                .precision(precision)
+                .trim_zeros(trim_zeros),
+        )
+    }
+
+    // ============================================================================
+    // Test format(number, Decimals)
+    // ============================================================================
+
+    mod test_format_decimals {
+        use super::*;
+
+        #[test]
+        fn should_show_fixed_decimals() {
+            // should show a fixed number of decimals
+            let opts = precision(Precision::Decimals(1), false, false);
+            assert_eq!(format(5400000, opts), "1.5h");
+            assert_eq!(format(3600000, opts), "1.0h");
+            assert_eq!(format(-5400000, opts), "-1.5h");
+            assert_eq!(format(500, opts), "500.0ms");
+
+            let opts = precision(Precision::Decimals(2), false, false);
+            assert_eq!(format(5400000, opts), "1.50h");
+            assert_eq!(format(194400000, opts), "2.25d");
+        }
+
+        #[test]
+        fn should_round_the_last_decimal() {
+            // should round the last decimal half away from zero
+            let opts = precision(Precision::Decimals(1), false, false);
+            assert_eq!(format(1250, opts), "1.3s");
+            assert_eq!(format(-1250, opts), "-1.3s");
+            assert_eq!(format(1249, opts), "1.2s");
+            assert_eq!(format(59990, opts), "60.0s");
+        }
+
+        #[test]
+        fn should_match_default_with_zero_decimals() {
+            // should match the default output with zero decimals
+            let opts = precision(Precision::Decimals(0), false, false);
+            assert_eq!(format(5400000, opts), "2h");
+            assert_eq!(format(234234234, opts), "3d");
+        }
+    }
+
+    // ============================================================================
+    // Test format(number, Significant)
+    // ============================================================================
+
+    mod test_format_significant {
+        use super::*;
+
+        #[test]
+        fn should_show_significant_digits() {
+            // should show a number of significant digits
+            let opts = precision(Precision::Significant(2), false, false);
+            assert_eq!(format(5400000, opts), "1.5h");
+            assert_eq!(format(12345678, opts), "3.4h");
+            assert_eq!(format(45000000, opts), "13h");
+            assert_eq!(format(150 * 86400000, opts), "4.9mo");
+        }
+
+        #[test]
+        fn should_round_to_tens_when_needed() {
+            // should round whole numbers to fewer significant digits
+            let opts = precision(Precision::Significant(1), false, false);
+            assert_eq!(format(456, opts), "500ms");
+            assert_eq!(format(45 * 60000, opts), "50m");
+        }
+
+        #[test]
+        fn should_handle_carry_into_a_new_digit() {
+            // should not add a digit when rounding carries over
+            let opts = precision(Precision::Significant(2), false, false);
+            assert_eq!(format(9960, opts), "10s");
+            let opts = precision(Precision::Significant(3), false, false);
+            assert_eq!(format(9996, opts), "10.0s");
+        }
+    }
+
+    // ============================================================================
+    // Test format(number, trim_zeros)
+    // ============================================================================
+
+    mod test_format_trim_zeros {
+        use super::*;
+
+        #[test]
+        fn should_trim_trailing_zeros() {
+            // should trim trailing zeros and the decimal point
+            let opts = precision(Precision::Decimals(2), true, false);
+            assert_eq!(format(5400000, opts), "1.5h");
+            assert_eq!(format(7200000, opts), "2h");
+            assert_eq!(format(194400000, opts), "2.25d");
+            assert_eq!(format(500, opts), "500ms");
+        }
+
+        #[test]
+        fn should_not_trim_whole_numbers() {
+            // should not trim zeros from whole numbers
+            let opts = precision(Precision::Significant(1), true, false);
+            assert_eq!(format(456, opts), "500ms");
+        }
+    }
+
+    // ============================================================================
+    // Test format(number, long, precision)
+    // ============================================================================
+
+    mod test_format_long_precision {
+        use super::*;
+
+        #[test]
+        fn should_pluralize_fractional_values() {
+            // should pluralize fractional values
+            let opts = precision(Precision::Decimals(1), false, true);
+            assert_eq!(format(5400000, opts), "1.5 hours");
+            assert_eq!(format(1800000, opts), "30.0 minutes");
+            assert_eq!(format(3600000, opts), "1.0 hours");
+            assert_eq!(format(-5400000, opts), "-1.5 hours");
+            assert_eq!(format(194400000, opts), "2.3 days");
+        }
+
+        #[test]
+        fn should_use_singular_for_exactly_one() {
+            // should use the singular when the value shown is exactly one
+            let opts = precision(Precision::Decimals(1), true, true);
+            assert_eq!(format(3600000, opts), "1 hour");
+            assert_eq!(format(-3600000, opts), "-1 hour");
+            assert_eq!(format(5400000, opts), "1.5 hours");
+        }
+    }
+}
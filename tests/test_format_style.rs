@@ -0,0 +1,142 @@
+use millis::{Options, Precision, Style, Unit, format, parse};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: i64 = 1000;
+    const MINUTE: i64 = 60 * SECOND;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    const WEEK: i64 = 7 * DAY;
+    const MONTH: i64 = 2629800000;
+    const YEAR: i64 = 31557600000;
+
+    fn styled(ms: i64, style: Style) -> String {
+        let opts = Options::new().style(style).extended_units(true);
+        format(ms, Some(opts))
+    }
+
+    // ============================================================================
+    // Test format(number, Style::Narrow)
+    // ============================================================================
+
+    mod test_format_narrow {
+        use super::*;
+
+        #[test]
+        fn should_be_the_default() {
+            // should be the default style
+            assert_eq!(styled(HOUR, Style::Narrow), format(HOUR, None));
+        }
+
+        #[test]
+        fn should_support_every_unit() {
+            // should write symbols without a space
+            assert_eq!(styled(500, Style::Narrow), "500ms");
+            assert_eq!(styled(SECOND, Style::Narrow), "1s");
+            assert_eq!(styled(MINUTE, Style::Narrow), "1m");
+            assert_eq!(styled(HOUR, Style::Narrow), "1h");
+            assert_eq!(styled(DAY, Style::Narrow), "1d");
+            assert_eq!(styled(WEEK, Style::Narrow), "1w");
+            assert_eq!(styled(MONTH, Style::Narrow), "1mo");
+            assert_eq!(styled(YEAR, Style::Narrow), "1y");
+            assert_eq!(styled(10 * YEAR, Style::Narrow), "1 decade");
+        }
+    }
+
+    // ============================================================================
+    // Test format(number, Style::Short)
+    // ============================================================================
+
+    mod test_format_short {
+        use super::*;
+
+        #[test]
+        fn should_support_every_unit() {
+            // should write abbreviations after a space
+            assert_eq!(styled(500, Style::Short), "500 ms");
+            assert_eq!(styled(SECOND, Style::Short), "1 sec");
+            assert_eq!(styled(MINUTE, Style::Short), "1 min");
+            assert_eq!(styled(HOUR, Style::Short), "1 hr");
+            assert_eq!(styled(DAY, Style::Short), "1 day");
+            assert_eq!(styled(WEEK, Style::Short), "1 wk");
+            assert_eq!(styled(MONTH, Style::Short), "1 mth");
+            assert_eq!(styled(YEAR, Style::Short), "1 yr");
+            assert_eq!(styled(10 * YEAR, Style::Short), "1 decade");
+            assert_eq!(styled(100 * YEAR, Style::Short), "1 century");
+            assert_eq!(styled(1000 * YEAR, Style::Short), "1 millennium");
+        }
+
+        #[test]
+        fn should_pluralize() {
+            // should pluralize abbreviations
+            assert_eq!(styled(10 * SECOND, Style::Short), "10 secs");
+            assert_eq!(styled(-10 * MINUTE, Style::Short), "-10 mins");
+            assert_eq!(styled(2 * HOUR, Style::Short), "2 hrs");
+            assert_eq!(styled(3 * WEEK, Style::Short), "3 wks");
+            assert_eq!(styled(5 * MONTH, Style::Short), "5 mths");
+            assert_eq!(styled(2 * YEAR, Style::Short), "2 yrs");
+        }
+
+        #[test]
+        fn should_support_precision() {
+            // should support precision options
+            let opts = Options::new()
+                .style(Style::Short)
+                .precision(Precision::Decimals(1));
+            assert_eq!(format(5400000, Some(opts)), "1.5 hrs");
+        }
+
+        #[test]
+        fn should_parse_back() {
+            // should produce strings that parse back
+            for ms in [
+                SECOND,
+                2 * MINUTE,
+                HOUR,
+                3 * DAY,
+                WEEK,
+                2 * WEEK,
+                MONTH,
+                4 * YEAR,
+            ] {
+                assert_eq!(parse(&styled(ms, Style::Short)).unwrap(), ms);
+            }
+        }
+    }
+
+    // ============================================================================
+    // Test format(number, Style::Long)
+    // ============================================================================
+
+    mod test_format_long_style {
+        use super::*;
+
+        #[test]
+        fn should_match_long_flag() {
+            // should match the long flag
+            for ms in [500, SECOND, 2 * HOUR, 3 * MONTH, -YEAR] {
+                let long = Options::new().long(true);
+                assert_eq!(
+                    format(ms, Some(long)),
+                    format(ms, Some(Options::new().style(Style::Long)))
+                );
+            }
+        }
+
+        #[test]
+        fn should_let_long_flag_take_precedence() {
+            // should prefer the long flag over the style
+            let opts = Options::new().long(true).style(Style::Short);
+            assert_eq!(format(HOUR, Some(opts)), "1 hour");
+        }
+
+        #[test]
+        fn should_support_forced_units() {
+            // should support every unit when forced
+            let opts = Options::new().style(Style::Long).unit(Unit::Fortnights);
+            assert_eq!(format(4 * WEEK, Some(opts)), "2 fortnights");
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use millis::format_template;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: i64 = 1000;
+    const MINUTE: i64 = 60 * SECOND;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    // ============================================================================
+    // Test format_template(number, template)
+    // ============================================================================
+
+    mod test_format_template {
+        use super::*;
+
+        #[test]
+        fn should_fill_in_fields() {
+            // should fill in every field
+            let ms = 2 * DAY + 3 * HOUR + 4 * MINUTE + 5 * SECOND + 6;
+            assert_eq!(
+                format_template(ms, "%d days, %H:%M:%S.%L").unwrap(),
+                "2 days, 03:04:05.006"
+            );
+            assert_eq!(
+                format_template(ms, "%dd %hh %mm %ss").unwrap(),
+                "2d 3h 4m 5s"
+            );
+        }
+
+        #[test]
+        fn should_expand_t() {
+            // should expand %T to %H:%M:%S
+            assert_eq!(
+                format_template(HOUR + 2 * MINUTE + 3 * SECOND, "%T").unwrap(),
+                "01:02:03"
+            );
+            assert_eq!(format_template(0, "%T.%L").unwrap(), "00:00:00.000");
+        }
+
+        #[test]
+        fn should_carry_overflow_into_largest_field() {
+            // should carry everything above the largest field into it
+            assert_eq!(format_template(2 * DAY + HOUR, "%H:%M").unwrap(), "49:00");
+            assert_eq!(
+                format_template(2 * DAY + HOUR, "%dd %Hh").unwrap(),
+                "2d 01h"
+            );
+            assert_eq!(format_template(90 * MINUTE, "%m min").unwrap(), "90 min");
+            assert_eq!(format_template(400 * DAY, "%d").unwrap(), "400");
+        }
+
+        #[test]
+        fn should_drop_time_below_smallest_field() {
+            // should truncate time below the smallest field
+            assert_eq!(format_template(HOUR + 59 * MINUTE, "%hh").unwrap(), "1h");
+            assert_eq!(format_template(1999, "%s").unwrap(), "1");
+        }
+
+        #[test]
+        fn should_support_padding_flags() {
+            // should support the -, _ and 0 padding flags
+            let ms = 5 * MINUTE + 7;
+            assert_eq!(format_template(ms, "%-M:%-L").unwrap(), "5:7");
+            assert_eq!(format_template(ms, "%_M:%_L").unwrap(), " 5:  7");
+            assert_eq!(format_template(ms, "%0m:%0d").unwrap(), "05:00");
+            assert_eq!(format_template(ms, "%-T").unwrap(), "0:5:0");
+        }
+
+        #[test]
+        fn should_keep_literal_text() {
+            // should keep literal text and %%
+            assert_eq!(
+                format_template(HOUR, "100%% done in %hh!").unwrap(),
+                "100% done in 1h!"
+            );
+            assert_eq!(format_template(HOUR, "no fields").unwrap(), "no fields");
+            assert_eq!(format_template(HOUR, "").unwrap(), "");
+            assert_eq!(format_template(HOUR, "時間: %h").unwrap(), "時間: 1");
+        }
+
+        #[test]
+        fn should_prefix_negative_durations() {
+            // should prefix negative durations with a sign
+            assert_eq!(
+                format_template(-(HOUR + 30 * MINUTE), "%H:%M").unwrap(),
+                "-01:30"
+            );
+            assert_eq!(format_template(-30 * SECOND, "%H:%M").unwrap(), "00:00");
+            assert_eq!(format_template(i64::MIN, "%d").unwrap(), "-106751991167");
+        }
+
+        #[test]
+        fn should_return_error_if_invalid() {
+            // should return an error for unknown or incomplete specifiers
+            assert!(format_template(HOUR, "%Y").is_err());
+            assert!(format_template(HOUR, "%H:%").is_err());
+            assert!(format_template(HOUR, "%-").is_err());
+            assert!(format_template(HOUR, "%-%").is_err());
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use millis::{HumanDuration, Options, Precision, Style, format, format_into};
+use std::fmt::{self, Write};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A writer into a fixed buffer, to check nothing needs a `String`
+    struct Buffer {
+        bytes: [u8; 32],
+        len: usize,
+    }
+
+    impl Buffer {
+        fn new() -> Self {
+            Buffer {
+                bytes: [0; 32],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            std::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            if end > self.bytes.len() {
+                return Err(fmt::Error);
+            }
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    // ============================================================================
+    // Test format_into(writer, number)
+    // ============================================================================
+
+    mod test_format_into {
+        use super::*;
+
+        #[test]
+        fn should_append_to_a_string() {
+            // should append to an existing string
+            let mut out = String::from("timeout=");
+            format_into(&mut out, 90000, None).unwrap();
+            assert_eq!(out, "timeout=2m");
+        }
+
+        #[test]
+        fn should_write_to_any_writer() {
+            // should write to any fmt::Write
+            let mut buf = Buffer::new();
+            let opts = Options::new().long(true).precision(Precision::Decimals(1));
+            format_into(&mut buf, -5400000, Some(opts)).unwrap();
+            assert_eq!(buf.as_str(), "-1.5 hours");
+        }
+
+        #[test]
+        fn should_return_writer_errors() {
+            // should pass on errors from the writer
+            let mut buf = Buffer::new();
+            buf.write_str(&"-".repeat(29)).unwrap();
+            assert!(format_into(&mut buf, 5400000, None).is_ok());
+            assert!(format_into(&mut buf, 5400000, None).is_err());
+        }
+
+        #[test]
+        fn should_match_format() {
+            // should write the same text as format()
+            let styles = [Style::Narrow, Style::Short, Style::Long];
+            for ms in [0, 1, -500, 1000, 90000, 5400000, -172800000, i64::MIN] {
+                for style in styles {
+                    let opts = Options::new().style(style);
+                    let mut out = String::new();
+                    format_into(&mut out, ms, Some(opts)).unwrap();
+                    assert_eq!(out, format(ms, Some(opts)));
+                }
+            }
+        }
+    }
+
+    // ============================================================================
+    // Test HumanDuration
+    // ============================================================================
+
+    mod test_human_duration {
+        use super::*;
+
+        #[test]
+        fn should_display_with_options() {
+            // should display with its options
+            assert_eq!(HumanDuration::from(60000).to_string(), "1m");
+            let short = Options::new().style(Style::Short);
+            assert_eq!(HumanDuration(-7200000, short).to_string(), "-2 hrs");
+        }
+
+        #[test]
+        fn should_use_long_style_when_alternate() {
+            // should use the long style for {:#}
+            assert_eq!(format!("{:#}", HumanDuration::from(60000)), "1 minute");
+            assert_eq!(
+                format!("{:#}", HumanDuration::from(500)),
+                "500 milliseconds"
+            );
+        }
+
+        #[test]
+        fn should_use_precision() {
+            // should show N decimals for {:.N}
+            let d = HumanDuration::from(5400000);
+            assert_eq!(format!("{:.1}", d), "1.5h");
+            assert_eq!(format!("{:.0}", d), "2h");
+            assert_eq!(format!("{:#.3}", d), "1.500 hours");
+        }
+
+        #[test]
+        fn should_pad_to_width() {
+            // should pad to the width without truncating
+            let d = HumanDuration::from(5400000);
+            assert_eq!(format!("[{:6}]", d), "[2h    ]");
+            assert_eq!(format!("[{:>6}]", d), "[    2h]");
+            assert_eq!(format!("[{:*^6}]", d), "[**2h**]");
+            assert_eq!(format!("[{:>6.1}]", d), "[  1.5h]");
+            assert_eq!(format!("[{:1}]", d), "[2h]");
+        }
+
+        #[test]
+        fn should_write_into_a_buffer() {
+            // should work with write! into any writer
+            let mut buf = Buffer::new();
+            write!(buf, "took {:#}", HumanDuration::from(3000)).unwrap();
+            assert_eq!(buf.as_str(), "took 3 seconds");
+        }
+    }
+}
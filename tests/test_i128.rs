@@ -0,0 +1,161 @@
+use millis::{Options, Precision, Style, Unit, format, format_i128, parse, parse_i128};
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR: i128 = 31557600000;
+
+    // ============================================================================
+    // Test format_i128(number)
+    // ============================================================================
+
+    mod test_format_i128 {
+        use super::*;
+
+        #[test]
+        fn should_format_the_i64_range() {
+            // should format the ends of the i64 range
+            assert_eq!(format_i128(i128::from(i64::MIN), None), "-292271023y");
+            assert_eq!(format_i128(i128::from(i64::MAX), None), "292271023y");
+            assert_eq!(format(i64::MIN, None), "-292271023y");
+        }
+
+        #[test]
+        fn should_format_beyond_i64() {
+            // should format values beyond the i64 range
+            assert_eq!(
+                format_i128(1_000_000_000_000 * YEAR, None),
+                "1000000000000y"
+            );
+            let long = Options::new().long(true).extended_units(true);
+            assert_eq!(
+                format_i128(-4_500_000_000 * YEAR, Some(long)),
+                "-4500000 millennia"
+            );
+        }
+
+        #[test]
+        fn should_not_panic_at_the_ends_of_i128() {
+            // should not panic for any style or option at the ends of i128
+            for ms in [i128::MIN, i128::MIN + 1, i128::MAX] {
+                for style in [Style::Narrow, Style::Short, Style::Long] {
+                    for precision in [
+                        None,
+                        Some(Precision::Decimals(3)),
+                        Some(Precision::Significant(2)),
+                    ] {
+                        for unit in [None, Some(Unit::Microseconds), Some(Unit::Millennia)] {
+                            let mut opts = Options::new().style(style);
+                            opts.precision = precision;
+                            opts.unit = unit;
+                            let text = format_i128(ms, Some(opts));
+                            assert_eq!(text.starts_with('-'), ms < 0, "{}", text);
+                        }
+                    }
+                }
+            }
+            assert_eq!(
+                format_i128(i128::MIN, None),
+                "-5391448762278159040348039893y"
+            );
+        }
+
+        #[test]
+        fn should_format_microseconds_beyond_u128() {
+            // should scale the ends of i128 to microseconds without clamping
+            let micros = Options::new().unit(Unit::Microseconds);
+            assert_eq!(
+                format_i128(i128::MAX, Some(micros)),
+                "170141183460469231731687303715884105727000µs"
+            );
+            assert_eq!(
+                format_i128(i128::MIN, Some(micros.long(true))),
+                "-170141183460469231731687303715884105728000 microseconds"
+            );
+            assert_eq!(
+                format_i128(i128::MAX, Some(micros.precision(Precision::Decimals(2)))),
+                "170141183460469231731687303715884105727000.00µs"
+            );
+            assert_eq!(
+                format_i128(i128::MAX, Some(micros.precision(Precision::Significant(2)))),
+                "170000000000000000000000000000000000000000µs"
+            );
+        }
+
+        proptest! {
+            #[test]
+            fn should_match_format_for_i64(x in any::<i64>()) {
+                // should match format() for every i64
+                prop_assert_eq!(format_i128(i128::from(x), None), format(x, None));
+            }
+        }
+    }
+
+    // ============================================================================
+    // Test parse_i128(string)
+    // ============================================================================
+
+    mod test_parse_i128 {
+        use super::*;
+
+        #[test]
+        fn should_match_parse() {
+            // should match parse() within the i64 range
+            for s in [
+                "2h",
+                "-1.5 hours",
+                "1h30m",
+                "½h",
+                "1500 µs",
+                "9223372036854775807",
+            ] {
+                assert_eq!(parse_i128(s).unwrap(), i128::from(parse(s).unwrap()));
+            }
+        }
+
+        #[test]
+        fn should_parse_beyond_i64() {
+            // should parse values beyond the i64 range exactly
+            assert_eq!(
+                parse_i128("9223372036854775808").unwrap(),
+                9223372036854775808
+            );
+            assert_eq!(
+                parse_i128("1000000000000y").unwrap(),
+                1_000_000_000_000 * YEAR
+            );
+            assert_eq!(
+                parse_i128("-4500000 millennia").unwrap(),
+                -4_500_000_000 * YEAR
+            );
+            assert_eq!(
+                parse_i128("-170141183460469231731687303715884105728").unwrap(),
+                i128::MIN
+            );
+            assert_eq!(
+                parse_i128("170141183460469231731687303715884105727").unwrap(),
+                i128::MAX
+            );
+        }
+
+        #[test]
+        fn should_return_error_when_out_of_range() {
+            // should return an error instead of saturating
+            assert!(parse_i128("170141183460469231731687303715884105728").is_err());
+            assert!(parse_i128("-170141183460469231731687303715884105729").is_err());
+            assert!(parse_i128("99999999999999999999999999999y").is_err());
+            assert!(parse_i128("9999999999999999999999999999.5y").is_err());
+        }
+
+        #[test]
+        fn should_round_trip_whole_years() {
+            // should parse formatted whole years back
+            for years in [1, -7, 1_000_000_000_000, -5_391_448_762_278_159_040] {
+                let ms = years * YEAR;
+                assert_eq!(parse_i128(&format_i128(ms, None)).unwrap(), ms);
+            }
+        }
+    }
+}
@@ -1,3 +1,6 @@
+// Spelled-out unit arithmetic (e.g. `-1 * 60 * 1000`) mirrors the upstream ms test suite.
+#![allow(clippy::identity_op, clippy::neg_multiply)]
+
 use millis::ms;
 
 #[cfg(test)]
@@ -0,0 +1,176 @@
+#![cfg(feature = "jiff")]
+
+use jiff::civil::date;
+use jiff::{Span, ToSpan};
+use millis::{MPolicy, Options, ParseOptions, Style, format_span, parse_span, parse_with};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styled(style: Style) -> Option<Options> {
+        Some(Options::new().style(style))
+    }
+
+    // ============================================================================
+    // Test parsing into Span
+    // ============================================================================
+
+    mod test_parse_span {
+        use super::*;
+
+        #[test]
+        fn should_keep_units() {
+            // should not collapse units into milliseconds
+            assert_eq!(parse_span("1mo").unwrap().fieldwise(), 1.month());
+            assert_eq!(parse_span("90m").unwrap().fieldwise(), 90.minutes());
+            assert_eq!(
+                parse_span("1y 2w 3d").unwrap().fieldwise(),
+                1.year().weeks(2).days(3)
+            );
+            assert_eq!(parse_span("500").unwrap().fieldwise(), 500.milliseconds());
+            assert_eq!(parse_span("1h 1h").unwrap().fieldwise(), 2.hours());
+        }
+
+        #[test]
+        fn should_map_extended_units() {
+            // should turn long units into years and fortnights into weeks
+            assert_eq!(parse_span("2 decades").unwrap().fieldwise(), 20.years());
+            assert_eq!(parse_span("1 century").unwrap().fieldwise(), 100.years());
+            assert_eq!(parse_span("1 fortnight").unwrap().fieldwise(), 2.weeks());
+        }
+
+        #[test]
+        fn should_carry_fractions() {
+            // should carry fractions of fixed units into smaller units
+            assert_eq!(
+                parse_span("1.5h").unwrap().fieldwise(),
+                1.hour().minutes(30)
+            );
+            assert_eq!(parse_span("½m").unwrap().fieldwise(), 30.seconds());
+            assert_eq!(
+                parse_span("2.5µs").unwrap().fieldwise(),
+                2.microseconds().nanoseconds(500)
+            );
+            assert_eq!(
+                parse_span("1.25s").unwrap().fieldwise(),
+                1.second().milliseconds(250)
+            );
+        }
+
+        #[test]
+        fn should_negate() {
+            // should apply the sign to the whole span
+            assert_eq!(
+                parse_span("-1d 12h").unwrap().fieldwise(),
+                (-1).day().hours(-12)
+            );
+            assert_eq!(
+                parse_span("-1.5h").unwrap().fieldwise(),
+                (-1).hour().minutes(-30)
+            );
+        }
+
+        #[test]
+        fn should_reject_fractional_calendar_units() {
+            // should report fractional days and longer units
+            assert!(parse_span("1.5mo").is_err());
+            assert!(parse_span("1.5d").is_err());
+            assert!(parse_span("½w").is_err());
+            assert!(parse_span("invalid").is_err());
+            assert!(parse_span("100000y").is_err());
+        }
+
+        #[test]
+        fn should_apply_to_dates() {
+            // should add calendar months
+            let span = parse_span("1mo").unwrap();
+            assert_eq!(
+                date(2024, 1, 31).checked_add(span).unwrap(),
+                date(2024, 2, 29)
+            );
+            assert_eq!(
+                date(2023, 1, 31).checked_add(span).unwrap(),
+                date(2023, 2, 28)
+            );
+        }
+    }
+
+    // ============================================================================
+    // Test formatting Span
+    // ============================================================================
+
+    mod test_format_span {
+        use super::*;
+
+        #[test]
+        fn should_format_narrow() {
+            // should join units without spaces
+            assert_eq!(format_span(1.year().months(2), None), "1y2mo");
+            assert_eq!(format_span(90.minutes(), None), "90m");
+            assert_eq!(format_span(1.week().milliseconds(5), None), "1w5ms");
+        }
+
+        #[test]
+        fn should_format_short_and_long() {
+            // should use abbreviations or names with plurals
+            let span = 1.month().days(2).hours(1);
+            assert_eq!(format_span(span, styled(Style::Short)), "1 mth 2 days 1 hr");
+            assert_eq!(
+                format_span(span, styled(Style::Long)),
+                "1 month 2 days 1 hour"
+            );
+        }
+
+        #[test]
+        fn should_format_sign_and_zero() {
+            // should prefix negative spans and format empty ones like format()
+            assert_eq!(format_span((-2).days().hours(-3), None), "-2d3h");
+            assert_eq!(format_span(Span::new(), None), "0ms");
+            assert_eq!(format_span(Span::new(), styled(Style::Short)), "0 ms");
+        }
+
+        #[test]
+        fn should_format_nanoseconds() {
+            // should show nanoseconds as a fraction of a microsecond
+            assert_eq!(format_span(1500.nanoseconds(), None), "1.5µs");
+            assert_eq!(format_span(1.microsecond().nanoseconds(5), None), "1.005µs");
+            assert_eq!(format_span(3000.nanoseconds(), None), "3µs");
+        }
+
+        #[test]
+        fn should_follow_m_policy_and_case() {
+            // should write minutes and months as format() does for the options
+            let span = 2.months().minutes(5);
+            let reject = Options::new().m_policy(MPolicy::Reject);
+            assert_eq!(format_span(span, Some(reject)), "2mo5min");
+            let strict = ParseOptions::new().m_policy(MPolicy::Reject);
+            assert!(parse_with("2mo5min", Some(strict)).is_ok());
+            assert!(parse_with("2mo5m", Some(strict)).is_err());
+
+            let case_sensitive = Options::new().case_sensitive(true);
+            assert_eq!(format_span(span, Some(case_sensitive)), "2M5m");
+            let strict = ParseOptions::new().case_sensitive(true);
+            assert_eq!(
+                parse_with("2M5m", Some(strict)).unwrap(),
+                parse_with("2mo5min", None).unwrap()
+            );
+        }
+
+        #[test]
+        fn should_round_trip() {
+            // should parse back to the same span
+            for span in [
+                1.year().months(2).days(3),
+                (-90).minutes(),
+                1.hour().seconds(1).nanoseconds(1),
+                2.weeks().milliseconds(999),
+            ] {
+                for options in [None, styled(Style::Short), styled(Style::Long)] {
+                    let text = format_span(span, options);
+                    assert_eq!(parse_span(&text).unwrap().fieldwise(), span, "{}", text);
+                }
+            }
+        }
+    }
+}
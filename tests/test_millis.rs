@@ -0,0 +1,124 @@
+use millis::{Millis, ms};
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============================================================================
+    // Test Millis parsing and display
+    // ============================================================================
+
+    mod test_millis_str {
+        use super::*;
+
+        #[test]
+        fn should_parse_from_str() {
+            // should parse with FromStr
+            assert_eq!("2h".parse::<Millis>().unwrap(), Millis(7200000));
+            assert_eq!("-1.5 hours".parse::<Millis>().unwrap(), Millis(-5400000));
+            assert!("invalid".parse::<Millis>().is_err());
+        }
+
+        #[test]
+        fn should_display() {
+            // should display like format()
+            assert_eq!(Millis(60000).to_string(), "1m");
+            assert_eq!(format!("{:#}", Millis(60000)), "1 minute");
+            assert_eq!(format!("{:.1}", Millis(5400000)), "1.5h");
+            assert_eq!(format!("{:>4}", Millis(1000)), "  1s");
+        }
+
+        #[test]
+        fn should_work_with_ms() {
+            // should convert to and from ms()
+            let timeout: Millis = ms("10s").unwrap().into();
+            assert_eq!(timeout, Millis(10000));
+            assert_eq!(ms(timeout).unwrap(), "10s");
+        }
+    }
+
+    // ============================================================================
+    // Test Millis arithmetic and ordering
+    // ============================================================================
+
+    mod test_millis_ops {
+        use super::*;
+
+        #[test]
+        fn should_add_and_subtract() {
+            // should add and subtract
+            assert_eq!(Millis(1000) + Millis(500), Millis(1500));
+            assert_eq!(Millis(1000) - Millis(1500), Millis(-500));
+            let mut total = Millis(1000);
+            total += Millis(1000);
+            total -= Millis(500);
+            assert_eq!(total, Millis(1500));
+        }
+
+        #[test]
+        fn should_multiply_and_negate() {
+            // should multiply by i64 and negate
+            assert_eq!(Millis(1500) * 3, Millis(4500));
+            assert_eq!(2 * Millis(1500), Millis(3000));
+            assert_eq!(-Millis(1500), Millis(-1500));
+        }
+
+        #[test]
+        fn should_sum() {
+            // should sum owned and borrowed values
+            let parts = [Millis(1000), Millis(2000), Millis(-500)];
+            assert_eq!(parts.iter().sum::<Millis>(), Millis(2500));
+            assert_eq!(parts.into_iter().sum::<Millis>(), Millis(2500));
+            assert_eq!(Vec::<Millis>::new().into_iter().sum::<Millis>(), Millis(0));
+        }
+
+        #[test]
+        fn should_order() {
+            // should order by length
+            let mut values = vec![Millis(1000), Millis(-1), Millis(0)];
+            values.sort();
+            assert_eq!(values, [Millis(-1), Millis(0), Millis(1000)]);
+            assert_eq!(values.iter().max(), Some(&Millis(1000)));
+        }
+    }
+
+    // ============================================================================
+    // Test Millis conversions
+    // ============================================================================
+
+    mod test_millis_conversions {
+        use super::*;
+
+        #[test]
+        fn should_convert_to_and_from_i64() {
+            // should convert to and from i64
+            assert_eq!(Millis::from(42), Millis(42));
+            assert_eq!(i64::from(Millis(-42)), -42);
+        }
+
+        #[test]
+        fn should_convert_to_and_from_duration() {
+            // should convert to and from Duration
+            assert_eq!(
+                Millis::try_from(Duration::from_millis(1500)).unwrap(),
+                Millis(1500)
+            );
+            assert_eq!(
+                Millis::try_from(Duration::from_micros(1999)).unwrap(),
+                Millis(1)
+            );
+            assert_eq!(
+                Duration::try_from(Millis(1500)).unwrap(),
+                Duration::from_millis(1500)
+            );
+        }
+
+        #[test]
+        fn should_reject_out_of_range_durations() {
+            // should return an error for values that do not fit
+            assert!(Duration::try_from(Millis(-1)).is_err());
+            assert!(Millis::try_from(Duration::MAX).is_err());
+        }
+    }
+}
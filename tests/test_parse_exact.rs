@@ -0,0 +1,145 @@
+use millis::{ParseOptions, RoundingMode, find_durations, parse, parse_with};
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rounded(s: &str, rounding: RoundingMode) -> i64 {
+        let opts = ParseOptions::new().rounding(rounding);
+        parse_with(s, Some(opts)).unwrap()
+    }
+
+    // ============================================================================
+    // Test parse(string) exactness
+    // ============================================================================
+
+    mod test_parse_exact {
+        use super::*;
+
+        #[test]
+        fn should_parse_integers_exactly() {
+            // should parse integers beyond 2^53 exactly
+            assert_eq!(parse("9007199254740993ms").unwrap(), 9007199254740993);
+            assert_eq!(parse("9223372036854775806").unwrap(), 9223372036854775806);
+            assert_eq!(parse("-9223372036854775807").unwrap(), -9223372036854775807);
+        }
+
+        #[test]
+        fn should_not_accumulate_binary_error() {
+            // should add decimal fractions without binary rounding error
+            assert_eq!(parse("0.1h0.2h").unwrap(), parse("0.3h").unwrap());
+            assert_eq!(parse("0.3h").unwrap(), 1080000);
+            assert_eq!(rounded("0.1s0.2s", RoundingMode::Floor), 300);
+            assert_eq!(rounded("1.005s", RoundingMode::Floor), 1005);
+            assert_eq!(parse("0.5y").unwrap(), 15778800000);
+        }
+
+        #[test]
+        fn should_round_fractions_of_large_values_once() {
+            // should round large fractional values according to the mode
+            let s = "4503599627370497.5ms";
+            assert_eq!(rounded(s, RoundingMode::Floor), 4503599627370497);
+            assert_eq!(rounded(s, RoundingMode::Ceil), 4503599627370498);
+            assert_eq!(rounded(s, RoundingMode::HalfEven), 4503599627370498);
+            assert_eq!(
+                rounded("9223372036854775806.5", RoundingMode::HalfEven),
+                9223372036854775806
+            );
+            assert_eq!(
+                rounded("9223372036854775806.5", RoundingMode::HalfUp),
+                9223372036854775807
+            );
+        }
+
+        #[test]
+        fn should_break_ties_by_mode() {
+            // should apply each rounding mode to exact ties
+            assert_eq!(rounded("0.5ms", RoundingMode::HalfUp), 1);
+            assert_eq!(rounded("0.5ms", RoundingMode::HalfEven), 0);
+            assert_eq!(rounded("1.5ms", RoundingMode::HalfEven), 2);
+            assert_eq!(rounded("2.5ms", RoundingMode::HalfEven), 2);
+            assert_eq!(rounded("-2.5ms", RoundingMode::HalfEven), -2);
+            assert_eq!(rounded("-2.5ms", RoundingMode::HalfUp), -3);
+            assert_eq!(rounded("-0.5ms", RoundingMode::Floor), -1);
+            assert_eq!(rounded("-0.5ms", RoundingMode::Ceil), 0);
+            assert_eq!(rounded("2500µs", RoundingMode::HalfEven), 2);
+            assert_eq!(rounded("3500µs", RoundingMode::HalfEven), 4);
+        }
+
+        #[test]
+        fn should_use_digits_beyond_the_24th_place() {
+            // should let far digits break ties and directed rounding
+            let above_half = "0.5000000000000000000000000001ms";
+            assert_eq!(rounded(above_half, RoundingMode::HalfEven), 1);
+            let above_one = "1.0000000000000000000000000001ms";
+            assert_eq!(rounded(above_one, RoundingMode::Floor), 1);
+            assert_eq!(rounded(above_one, RoundingMode::Ceil), 2);
+            assert_eq!(
+                rounded("-1.0000000000000000000000000001ms", RoundingMode::Floor),
+                -2
+            );
+            assert_eq!(
+                rounded("0.0000000000000000000000000000ms", RoundingMode::Ceil),
+                0
+            );
+        }
+
+        #[test]
+        fn should_parse_vulgar_fractions_exactly() {
+            // should treat vulgar fractions as exact ratios
+            assert_eq!(parse("⅓h").unwrap(), 1200000);
+            assert_eq!(parse("1⅔ms").unwrap(), 2);
+            assert_eq!(parse("⅔s").unwrap(), 667);
+            assert_eq!(rounded("⅓ms", RoundingMode::Ceil), 1);
+            assert_eq!(rounded("⅙ms", RoundingMode::HalfUp), 0);
+            assert_eq!(rounded("1½ms", RoundingMode::HalfEven), 2);
+            assert_eq!(rounded("⅓h⅔h", RoundingMode::Floor), 3600000);
+        }
+
+        #[test]
+        fn should_reject_values_beyond_i64() {
+            // should report values beyond the i64 range rather than clamp them
+            assert_eq!(
+                parse("9223372036854775808").unwrap_err(),
+                "Duration out of range. value=\"9223372036854775808\""
+            );
+            assert!(parse("9999999999999999999999999999999999999999y").is_err());
+            assert!(parse("-9999999999999999999999999999999999999999y").is_err());
+            assert_eq!(parse("-9223372036854775808").unwrap(), i64::MIN);
+        }
+
+        #[test]
+        fn should_apply_to_find_durations() {
+            // should parse found durations exactly too
+            let found: Vec<i64> = find_durations("took 9007199254740993ms, then ⅓h")
+                .map(|m| m.millis)
+                .collect();
+            assert_eq!(found, [9007199254740993, 1200000]);
+
+            // should skip durations beyond the i64 range
+            let found: Vec<&str> = find_durations("took 300000000y, then 1h")
+                .map(|m| m.text)
+                .collect();
+            assert_eq!(found, ["1h"]);
+        }
+
+        proptest! {
+            #[test]
+            fn should_parse_any_i64(x in any::<i64>()) {
+                // should parse every i64 exactly
+                prop_assert_eq!(parse(&x.to_string()).unwrap(), x);
+                prop_assert_eq!(parse(&format!("{}ms", x)).unwrap(), x);
+            }
+
+            #[test]
+            fn should_round_halves_once(x in 0i64..i64::MAX) {
+                // should round x + 0.5 according to the mode
+                let s = format!("{}.5", x);
+                prop_assert_eq!(rounded(&s, RoundingMode::Floor), x);
+                prop_assert_eq!(rounded(&s, RoundingMode::HalfUp), x + 1);
+                prop_assert_eq!(rounded(&s, RoundingMode::HalfEven), x + x % 2);
+            }
+        }
+    }
+}
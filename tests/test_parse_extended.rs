@@ -0,0 +1,60 @@
+use millis::parse;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR: i64 = 31557600000;
+
+    // ============================================================================
+    // Test parse(extended units)
+    // ============================================================================
+
+    mod test_parse_extended_units {
+        use super::*;
+
+        #[test]
+        fn should_convert_fortnights_to_ms() {
+            // should convert fortnights to ms
+            assert_eq!(parse("1 fortnight").unwrap(), 1209600000);
+            assert_eq!(parse("2 fortnights").unwrap(), 2419200000);
+        }
+
+        #[test]
+        fn should_convert_decades_to_ms() {
+            // should convert decades to ms
+            assert_eq!(parse("1 decade").unwrap(), 10 * YEAR);
+            assert_eq!(parse("2 decades").unwrap(), 20 * YEAR);
+        }
+
+        #[test]
+        fn should_convert_centuries_to_ms() {
+            // should convert centuries to ms
+            assert_eq!(parse("1 century").unwrap(), 100 * YEAR);
+            assert_eq!(parse("2 centuries").unwrap(), 200 * YEAR);
+        }
+
+        #[test]
+        fn should_convert_millennia_to_ms() {
+            // should convert millennia to ms
+            assert_eq!(parse("1 millennium").unwrap(), 1000 * YEAR);
+            assert_eq!(parse("2 millennia").unwrap(), 2000 * YEAR);
+        }
+
+        #[test]
+        fn should_work_with_decimals_and_negatives() {
+            // should work with decimals and negative values
+            assert_eq!(parse("1.5 decades").unwrap(), 15 * YEAR);
+            assert_eq!(parse("-1 Century").unwrap(), -100 * YEAR);
+        }
+
+        #[test]
+        fn should_not_abbreviate_extended_units() {
+            // should reject abbreviations that are also ordinary words
+            assert!(parse("3dec").is_err());
+            assert!(parse("5 cents").is_err());
+            assert!(parse("2 mill").is_err());
+            assert!(parse("2 millenniums").is_err());
+        }
+    }
+}
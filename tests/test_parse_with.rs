@@ -0,0 +1,103 @@
+use millis::{MPolicy, ParseOptions, parse_with};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CASE_SENSITIVE: ParseOptions = ParseOptions::new().case_sensitive(true);
+
+    const REJECT_M: ParseOptions = ParseOptions::new().m_policy(MPolicy::Reject);
+
+    // ============================================================================
+    // Test parse_with(string, None)
+    // ============================================================================
+
+    mod test_parse_with_defaults {
+        use super::*;
+
+        #[test]
+        fn should_behave_like_parse() {
+            // should behave like parse() without options
+            assert_eq!(parse_with("1m", None).unwrap(), 60000);
+            assert_eq!(parse_with("3M", None).unwrap(), 180000);
+            assert_eq!(parse_with("1 Day", None).unwrap(), 86400000);
+            assert!(parse_with("", None).is_err());
+        }
+    }
+
+    // ============================================================================
+    // Test parse_with(string, case_sensitive)
+    // ============================================================================
+
+    mod test_parse_with_case_sensitive {
+        use super::*;
+
+        #[test]
+        fn should_read_uppercase_m_as_months() {
+            // should read "M" as months and "m" as minutes
+            assert_eq!(parse_with("3M", Some(CASE_SENSITIVE)).unwrap(), 7889400000);
+            assert_eq!(parse_with("3m", Some(CASE_SENSITIVE)).unwrap(), 180000);
+            assert_eq!(
+                parse_with("-1 M", Some(CASE_SENSITIVE)).unwrap(),
+                -2629800000
+            );
+        }
+
+        #[test]
+        fn should_accept_lowercase_units() {
+            // should accept lowercase units
+            assert_eq!(parse_with("2h", Some(CASE_SENSITIVE)).unwrap(), 7200000);
+            assert_eq!(parse_with("1 day", Some(CASE_SENSITIVE)).unwrap(), 86400000);
+            assert_eq!(parse_with("2mo", Some(CASE_SENSITIVE)).unwrap(), 5259600000);
+            assert_eq!(parse_with("100", Some(CASE_SENSITIVE)).unwrap(), 100);
+        }
+
+        #[test]
+        fn should_reject_other_uppercase_units() {
+            // should reject uppercase units other than "M"
+            assert!(parse_with("1H", Some(CASE_SENSITIVE)).is_err());
+            assert!(parse_with("1 Day", Some(CASE_SENSITIVE)).is_err());
+            assert!(parse_with("1MS", Some(CASE_SENSITIVE)).is_err());
+            assert!(parse_with("1Mo", Some(CASE_SENSITIVE)).is_err());
+        }
+    }
+
+    // ============================================================================
+    // Test parse_with(string, m_policy = Reject)
+    // ============================================================================
+
+    mod test_parse_with_reject_m {
+        use super::*;
+
+        #[test]
+        fn should_reject_single_letter_m() {
+            // should reject "m" and "M"
+            assert!(parse_with("5m", Some(REJECT_M)).is_err());
+            assert!(parse_with("5M", Some(REJECT_M)).is_err());
+            assert!(
+                parse_with("5m", Some(REJECT_M))
+                    .unwrap_err()
+                    .contains("Ambiguous unit")
+            );
+        }
+
+        #[test]
+        fn should_reject_m_when_case_sensitive() {
+            // should reject "M" even when it would mean months
+            let opts = ParseOptions::new()
+                .case_sensitive(true)
+                .m_policy(MPolicy::Reject);
+            assert!(parse_with("5M", Some(opts)).is_err());
+            assert!(parse_with("5m", Some(opts)).is_err());
+        }
+
+        #[test]
+        fn should_accept_unambiguous_names() {
+            // should accept unambiguous minute and month names
+            assert_eq!(parse_with("5min", Some(REJECT_M)).unwrap(), 300000);
+            assert_eq!(parse_with("5 minutes", Some(REJECT_M)).unwrap(), 300000);
+            assert_eq!(parse_with("5mo", Some(REJECT_M)).unwrap(), 13149000000);
+            assert_eq!(parse_with("5ms", Some(REJECT_M)).unwrap(), 5);
+        }
+    }
+}
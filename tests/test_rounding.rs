@@ -0,0 +1,130 @@
+use millis::{
+    Options, ParseOptions, Precision, RoundingMode, find_durations_with, format, parse_with,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_rounded(s: &str, rounding: RoundingMode) -> i64 {
+        let opts = ParseOptions::new().rounding(rounding);
+        parse_with(s, Some(opts)).unwrap()
+    }
+
+    fn format_rounded(ms: i64, rounding: RoundingMode) -> String {
+        let opts = Options::new().rounding(rounding);
+        format(ms, Some(opts))
+    }
+
+    // ============================================================================
+    // Test parse_with(string, rounding)
+    // ============================================================================
+
+    mod test_parse_rounding {
+        use super::*;
+
+        #[test]
+        fn should_round_half_up_by_default() {
+            // should round ties away from zero by default
+            assert_eq!(parse_rounded("2.5ms", RoundingMode::HalfUp), 3);
+            assert_eq!(parse_rounded("-2.5ms", RoundingMode::HalfUp), -3);
+            assert_eq!(parse_with("2.5ms", None).unwrap(), 3);
+        }
+
+        #[test]
+        fn should_floor() {
+            // should round towards negative infinity
+            assert_eq!(parse_rounded("1.9ms", RoundingMode::Floor), 1);
+            assert_eq!(parse_rounded("-1.1ms", RoundingMode::Floor), -2);
+        }
+
+        #[test]
+        fn should_ceil() {
+            // should round towards positive infinity
+            assert_eq!(parse_rounded("1.1ms", RoundingMode::Ceil), 2);
+            assert_eq!(parse_rounded("-1.9ms", RoundingMode::Ceil), -1);
+        }
+
+        #[test]
+        fn should_truncate() {
+            // should round towards zero
+            assert_eq!(parse_rounded("1.9ms", RoundingMode::Truncate), 1);
+            assert_eq!(parse_rounded("-1.9ms", RoundingMode::Truncate), -1);
+        }
+
+        #[test]
+        fn should_round_half_even() {
+            // should round ties to the even neighbour
+            assert_eq!(parse_rounded("2.5ms", RoundingMode::HalfEven), 2);
+            assert_eq!(parse_rounded("3.5ms", RoundingMode::HalfEven), 4);
+            assert_eq!(parse_rounded("-2.5ms", RoundingMode::HalfEven), -2);
+            assert_eq!(parse_rounded("2.6ms", RoundingMode::HalfEven), 3);
+        }
+
+        #[test]
+        fn should_apply_to_found_durations() {
+            // should apply to durations found in text
+            let opts = ParseOptions::new().rounding(RoundingMode::Ceil);
+            let found: Vec<_> = find_durations_with("took 1.2 ms", Some(opts)).collect();
+            assert_eq!(found[0].millis, 2);
+        }
+    }
+
+    // ============================================================================
+    // Test format(number, rounding)
+    // ============================================================================
+
+    mod test_format_rounding {
+        use super::*;
+
+        #[test]
+        fn should_round_half_up_by_default() {
+            // should round ties away from zero by default
+            assert_eq!(format_rounded(1500, RoundingMode::HalfUp), "2s");
+            assert_eq!(format_rounded(-1500, RoundingMode::HalfUp), "-2s");
+        }
+
+        #[test]
+        fn should_floor() {
+            // should floor, e.g. for countdowns
+            assert_eq!(format_rounded(119000, RoundingMode::Floor), "1m");
+            assert_eq!(format_rounded(-61000, RoundingMode::Floor), "-2m");
+        }
+
+        #[test]
+        fn should_ceil() {
+            // should ceil, e.g. for budgets
+            assert_eq!(format_rounded(61000, RoundingMode::Ceil), "2m");
+            assert_eq!(format_rounded(-119000, RoundingMode::Ceil), "-1m");
+        }
+
+        #[test]
+        fn should_truncate() {
+            // should round towards zero
+            assert_eq!(format_rounded(119000, RoundingMode::Truncate), "1m");
+            assert_eq!(format_rounded(-119000, RoundingMode::Truncate), "-1m");
+        }
+
+        #[test]
+        fn should_round_half_even() {
+            // should round ties to the even neighbour
+            assert_eq!(format_rounded(2500, RoundingMode::HalfEven), "2s");
+            assert_eq!(format_rounded(3500, RoundingMode::HalfEven), "4s");
+            assert_eq!(format_rounded(-2500, RoundingMode::HalfEven), "-2s");
+        }
+
+        #[test]
+        fn should_apply_to_decimals() {
+            // should apply to the last decimal shown
+            let opts = Options::new()
+                .precision(Precision::Decimals(1))
+                .rounding(RoundingMode::Floor);
+            assert_eq!(format(5940000, Some(opts)), "1.6h");
+            assert_eq!(format(-5940000, Some(opts)), "-1.7h");
+
+            let opts = opts.rounding(RoundingMode::Ceil);
+            assert_eq!(format(5760000, Some(opts)), "1.6h");
+            assert_eq!(format(-5940000, Some(opts)), "-1.6h");
+        }
+    }
+}
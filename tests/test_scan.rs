@@ -0,0 +1,102 @@
+use millis::{DurationMatch, find_durations};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(text: &str) -> Vec<(&str, i64)> {
+        find_durations(text).map(|m| (m.text, m.millis)).collect()
+    }
+
+    // ============================================================================
+    // Test find_durations(text)
+    // ============================================================================
+
+    mod test_find_durations {
+        use super::*;
+
+        #[test]
+        fn should_find_every_duration() {
+            // should find every duration in order of appearance
+            assert_eq!(
+                values("restarted after 45 seconds, then waited 2h"),
+                vec![("45 seconds", 45000), ("2h", 7200000)]
+            );
+        }
+
+        #[test]
+        fn should_report_byte_spans() {
+            // should report byte spans into the original text
+            let text = "timeout: 1.5 hrs (was 30m)";
+            let found: Vec<DurationMatch> = find_durations(text).collect();
+            assert_eq!(found.len(), 2);
+            assert_eq!(found[0].range(), 9..16);
+            assert_eq!(&text[found[0].range()], "1.5 hrs");
+            assert_eq!(found[0].millis, 5400000);
+            assert_eq!(found[1].start, 22);
+            assert_eq!(found[1].end, 25);
+            assert_eq!(found[1].millis, 1800000);
+        }
+
+        #[test]
+        fn should_return_nothing_without_durations() {
+            // should return nothing when the text has no durations
+            assert!(values("").is_empty());
+            assert!(values("no durations here").is_empty());
+            assert!(values("3 servers and 12 users").is_empty());
+        }
+
+        #[test]
+        fn should_be_case_insensitive() {
+            // should be case-insensitive
+            assert_eq!(values("waited 2 Days"), vec![("2 Days", 172800000)]);
+        }
+
+        #[test]
+        fn should_support_negative_values() {
+            // should support negative values
+            assert_eq!(values("offset -1h applied"), vec![("-1h", -3600000)]);
+        }
+
+        #[test]
+        fn should_treat_dashes_between_numbers_as_ranges() {
+            // should not read the dash in a range as a minus sign
+            assert_eq!(values("10-15 minutes"), vec![("15 minutes", 900000)]);
+        }
+
+        #[test]
+        fn should_ignore_numbers_inside_words() {
+            // should ignore numbers glued to surrounding words
+            assert!(values("build v2s failed").is_empty());
+            assert!(values("5mode 3hours2").is_empty());
+            assert!(values("version 1.2.3s").is_empty());
+        }
+
+        #[test]
+        fn should_ignore_ordinary_words() {
+            // should not read words such as "cents" as units
+            assert!(values("costs 5 cents each").is_empty());
+            assert!(values("ran 2 mill laps").is_empty());
+        }
+
+        #[test]
+        fn should_find_compound_durations() {
+            // should read consecutive parts as one duration, like parse()
+            assert_eq!(values("took 1h30m total"), vec![("1h30m", 5400000)]);
+            assert_eq!(values("waited 1h 30m"), vec![("1h 30m", 5400000)]);
+            assert_eq!(values("ETA -1d 12h."), vec![("-1d 12h", -129600000)]);
+            assert_eq!(values("after 1mo30d"), vec![("1mo30d", 5221800000)]);
+            assert_eq!(
+                values("1h and 30m"),
+                vec![("1h", 3600000), ("30m", 1800000)]
+            );
+        }
+
+        #[test]
+        fn should_prefer_longer_unit_names() {
+            // should match the full unit name rather than a prefix
+            assert_eq!(values("after 5 mo"), vec![("5 mo", 13149000000)]);
+            assert_eq!(values("after 5 mins."), vec![("5 mins", 300000)]);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+#![cfg(feature = "serde")]
+
+use millis::Millis;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Short {
+        #[serde(with = "millis::serde")]
+        value: i64,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Long {
+        #[serde(with = "millis::serde::long")]
+        value: i64,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Iso {
+        #[serde(with = "millis::serde::iso")]
+        value: i64,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Number {
+        #[serde(with = "millis::serde::number")]
+        value: i64,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Unsigned {
+        #[serde(with = "millis::serde::short")]
+        value: u64,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Std {
+        #[serde(with = "millis::serde")]
+        value: Duration,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Newtype {
+        #[serde(with = "millis::serde")]
+        value: Millis,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Optional {
+        #[serde(with = "millis::serde", default)]
+        value: Option<Duration>,
+    }
+
+    // ============================================================================
+    // Test deserializing numbers and strings
+    // ============================================================================
+
+    mod test_deserialize {
+        use super::*;
+
+        #[test]
+        fn should_accept_numbers() {
+            // should read numbers as milliseconds
+            let short: Short = serde_json::from_str(r#"{"value":1500}"#).unwrap();
+            assert_eq!(short.value, 1500);
+            let short: Short = serde_json::from_str(r#"{"value":-1500}"#).unwrap();
+            assert_eq!(short.value, -1500);
+            let short: Short = serde_json::from_str(r#"{"value":1.5}"#).unwrap();
+            assert_eq!(short.value, 2);
+        }
+
+        #[test]
+        fn should_accept_strings() {
+            // should parse time strings
+            let short: Short = serde_json::from_str(r#"{"value":"1.5h"}"#).unwrap();
+            assert_eq!(short.value, 5400000);
+            let short: Short = serde_json::from_str(r#"{"value":"1h 30m"}"#).unwrap();
+            assert_eq!(short.value, 5400000);
+            let short: Short = serde_json::from_str(r#"{"value":"-2 days"}"#).unwrap();
+            assert_eq!(short.value, -172800000);
+        }
+
+        #[test]
+        fn should_accept_iso() {
+            // should parse ISO 8601 durations
+            let short: Short = serde_json::from_str(r#"{"value":"PT1H30M"}"#).unwrap();
+            assert_eq!(short.value, 5400000);
+            let short: Short = serde_json::from_str(r#"{"value":"P1DT0.5S"}"#).unwrap();
+            assert_eq!(short.value, 86400500);
+            let short: Short = serde_json::from_str(r#"{"value":"-PT1,5S"}"#).unwrap();
+            assert_eq!(short.value, -1500);
+            let short: Short = serde_json::from_str(r#"{"value":"P2W"}"#).unwrap();
+            assert_eq!(short.value, 1209600000);
+        }
+
+        #[test]
+        fn should_reject_invalid_input() {
+            // should report invalid strings and ISO durations
+            assert!(serde_json::from_str::<Short>(r#"{"value":"invalid"}"#).is_err());
+            assert!(serde_json::from_str::<Short>(r#"{"value":"P"}"#).is_err());
+            assert!(serde_json::from_str::<Short>(r#"{"value":"PT"}"#).is_err());
+            assert!(serde_json::from_str::<Short>(r#"{"value":"P1H"}"#).is_err());
+            assert!(serde_json::from_str::<Short>(r#"{"value":true}"#).is_err());
+        }
+
+        #[test]
+        fn should_check_ranges() {
+            // should reject values the field cannot hold
+            assert!(serde_json::from_str::<Short>(r#"{"value":"1e30 years"}"#).is_err());
+            assert!(serde_json::from_str::<Unsigned>(r#"{"value":"-1s"}"#).is_err());
+            assert!(serde_json::from_str::<Std>(r#"{"value":-1}"#).is_err());
+
+            let unsigned: Unsigned =
+                serde_json::from_str(r#"{"value":18446744073709551615}"#).unwrap();
+            assert_eq!(unsigned.value, u64::MAX);
+        }
+
+        #[test]
+        fn should_read_toml() {
+            // should work with other formats
+            let std: Std = toml::from_str(r#"value = "30s""#).unwrap();
+            assert_eq!(std.value, Duration::from_secs(30));
+            let std: Std = toml::from_str("value = 250").unwrap();
+            assert_eq!(std.value, Duration::from_millis(250));
+        }
+    }
+
+    // ============================================================================
+    // Test serializing in each format
+    // ============================================================================
+
+    mod test_serialize {
+        use super::*;
+
+        #[test]
+        fn should_serialize_short() {
+            // should use the shortest exact string
+            let json = serde_json::to_string(&Short { value: 5400000 }).unwrap();
+            assert_eq!(json, r#"{"value":"90m"}"#);
+            let json = serde_json::to_string(&Short { value: 1234 }).unwrap();
+            assert_eq!(json, r#"{"value":"1234"}"#);
+            let json = serde_json::to_string(&Unsigned { value: u64::MAX }).unwrap();
+            let unsigned: Unsigned = serde_json::from_str(&json).unwrap();
+            assert_eq!(unsigned.value, u64::MAX);
+        }
+
+        #[test]
+        fn should_serialize_long() {
+            // should use unit names
+            let json = serde_json::to_string(&Long { value: 5400000 }).unwrap();
+            assert_eq!(json, r#"{"value":"1 hour 30 minutes"}"#);
+            let json = serde_json::to_string(&Long { value: 172800500 }).unwrap();
+            assert_eq!(json, r#"{"value":"2 days 500 milliseconds"}"#);
+            let json = serde_json::to_string(&Long { value: 86460000 }).unwrap();
+            assert_eq!(json, r#"{"value":"1 day 1 minute"}"#);
+            let json = serde_json::to_string(&Long { value: -1 }).unwrap();
+            assert_eq!(json, r#"{"value":"-1 millisecond"}"#);
+            let json = serde_json::to_string(&Long { value: 0 }).unwrap();
+            assert_eq!(json, r#"{"value":"0 milliseconds"}"#);
+        }
+
+        #[test]
+        fn should_serialize_iso() {
+            // should use days and time
+            let json = serde_json::to_string(&Iso { value: 5400000 }).unwrap();
+            assert_eq!(json, r#"{"value":"PT1H30M"}"#);
+            let json = serde_json::to_string(&Iso { value: 90000000 }).unwrap();
+            assert_eq!(json, r#"{"value":"P1DT1H"}"#);
+            let json = serde_json::to_string(&Iso { value: -1500 }).unwrap();
+            assert_eq!(json, r#"{"value":"-PT1.5S"}"#);
+            let json = serde_json::to_string(&Iso { value: 0 }).unwrap();
+            assert_eq!(json, r#"{"value":"PT0S"}"#);
+        }
+
+        #[test]
+        fn should_serialize_number() {
+            // should write plain milliseconds
+            let json = serde_json::to_string(&Number { value: 5400000 }).unwrap();
+            assert_eq!(json, r#"{"value":5400000}"#);
+            let number: Number = serde_json::from_str(r#"{"value":"1m"}"#).unwrap();
+            assert_eq!(number.value, 60000);
+        }
+
+        #[test]
+        fn should_serialize_duration_and_millis() {
+            // should drop sub-millisecond parts of a Duration
+            let std = Std {
+                value: Duration::from_micros(1500),
+            };
+            assert_eq!(serde_json::to_string(&std).unwrap(), r#"{"value":"1"}"#);
+            let newtype = Newtype {
+                value: Millis(60000),
+            };
+            assert_eq!(
+                serde_json::to_string(&newtype).unwrap(),
+                r#"{"value":"1m"}"#
+            );
+        }
+
+        #[test]
+        fn should_handle_options() {
+            // should read and write missing values
+            let some = Optional {
+                value: Some(Duration::from_secs(2)),
+            };
+            let json = serde_json::to_string(&some).unwrap();
+            assert_eq!(json, r#"{"value":"2s"}"#);
+            assert_eq!(serde_json::from_str::<Optional>(&json).unwrap(), some);
+
+            let none = Optional { value: None };
+            let json = serde_json::to_string(&none).unwrap();
+            assert_eq!(json, r#"{"value":null}"#);
+            assert_eq!(serde_json::from_str::<Optional>(&json).unwrap(), none);
+            assert_eq!(serde_json::from_str::<Optional>("{}").unwrap(), none);
+        }
+    }
+
+    // ============================================================================
+    // Test round trips
+    // ============================================================================
+
+    mod test_round_trip {
+        use super::*;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn should_round_trip_every_format(value: i64) {
+                // should read back every format exactly
+                let json = serde_json::to_string(&Short { value }).unwrap();
+                prop_assert_eq!(serde_json::from_str::<Short>(&json).unwrap().value, value);
+                let json = serde_json::to_string(&Long { value }).unwrap();
+                prop_assert_eq!(serde_json::from_str::<Long>(&json).unwrap().value, value);
+                let json = serde_json::to_string(&Iso { value }).unwrap();
+                prop_assert_eq!(serde_json::from_str::<Iso>(&json).unwrap().value, value);
+                let json = serde_json::to_string(&Number { value }).unwrap();
+                prop_assert_eq!(serde_json::from_str::<Number>(&json).unwrap().value, value);
+            }
+        }
+    }
+}
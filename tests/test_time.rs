@@ -0,0 +1,183 @@
+#![cfg(feature = "time")]
+
+use millis::{
+    Millis, Options, Style, add_duration, format_time_duration, ms, parse, parse_time_duration,
+    sub_duration,
+};
+use time::Duration;
+use time::macros::datetime;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============================================================================
+    // Test parsing into time::Duration
+    // ============================================================================
+
+    mod test_parse_time_duration {
+        use super::*;
+
+        #[test]
+        fn should_parse_like_parse() {
+            // should agree with parse() for whole milliseconds
+            for s in ["2h", "1d 12h", "-1.5 hours", "100", "1 week", "-1y"] {
+                let duration = parse_time_duration(s).unwrap();
+                assert_eq!(
+                    duration.whole_milliseconds(),
+                    i128::from(parse(s).unwrap()),
+                    "{}",
+                    s
+                );
+            }
+        }
+
+        #[test]
+        fn should_keep_sub_millisecond_precision() {
+            // should keep nanoseconds, rounding ties away from zero
+            assert_eq!(
+                parse_time_duration("1.5 µs").unwrap(),
+                Duration::nanoseconds(1500)
+            );
+            assert_eq!(
+                parse_time_duration("-0.5 µs").unwrap(),
+                Duration::nanoseconds(-500)
+            );
+            assert_eq!(
+                parse_time_duration("-1.0000000005s").unwrap(),
+                Duration::new(-1, -1)
+            );
+        }
+
+        #[test]
+        fn should_reject_invalid_and_out_of_range() {
+            // should report errors
+            assert!(parse_time_duration("invalid").is_err());
+            assert!(parse_time_duration("").is_err());
+            assert!(parse_time_duration("1000000000000 millennia").is_err());
+        }
+    }
+
+    // ============================================================================
+    // Test formatting time::Duration
+    // ============================================================================
+
+    mod test_format_time_duration {
+        use super::*;
+
+        #[test]
+        fn should_format_like_format() {
+            // should format with options
+            assert_eq!(format_time_duration(Duration::hours(2), None), "2h");
+            assert_eq!(format_time_duration(Duration::seconds(-90), None), "-2m");
+            let long = Options::new().style(Style::Long);
+            assert_eq!(
+                format_time_duration(Duration::days(-3), Some(long)),
+                "-3 days"
+            );
+        }
+
+        #[test]
+        fn should_round_to_milliseconds() {
+            // should round half away from zero
+            assert_eq!(
+                format_time_duration(Duration::microseconds(1499), None),
+                "1ms"
+            );
+            assert_eq!(
+                format_time_duration(Duration::microseconds(-1500), None),
+                "-2ms"
+            );
+        }
+
+        #[test]
+        fn should_format_extremes() {
+            // should not overflow at the ends of the range
+            assert!(!format_time_duration(Duration::MAX, None).is_empty());
+            assert!(format_time_duration(Duration::MIN, None).starts_with('-'));
+        }
+
+        #[test]
+        fn should_work_with_ms() {
+            // should format through ms()
+            assert_eq!(ms(Duration::minutes(-1)).unwrap(), "-1m");
+        }
+    }
+
+    // ============================================================================
+    // Test conversions with Millis
+    // ============================================================================
+
+    mod test_convert {
+        use super::*;
+
+        #[test]
+        fn should_convert_both_ways() {
+            // should keep the sign
+            assert_eq!(Duration::from(Millis(-1500)), Duration::milliseconds(-1500));
+            assert_eq!(
+                Millis::try_from(Duration::seconds(-2)).unwrap(),
+                Millis(-2000)
+            );
+            assert_eq!(
+                Millis::try_from(Duration::microseconds(1999)).unwrap(),
+                Millis(1)
+            );
+        }
+
+        #[test]
+        fn should_reject_out_of_range() {
+            // should report durations beyond i64 milliseconds
+            assert!(Millis::try_from(Duration::MAX).is_err());
+            assert!(Millis::try_from(Duration::MIN).is_err());
+        }
+    }
+
+    // ============================================================================
+    // Test OffsetDateTime helpers
+    // ============================================================================
+
+    mod test_date_time {
+        use super::*;
+
+        #[test]
+        fn should_add_and_subtract() {
+            // should move by the parsed duration
+            let start = datetime!(2024-02-28 23:00 UTC);
+            assert_eq!(
+                add_duration(start, "2h").unwrap(),
+                datetime!(2024-02-29 01:00 UTC)
+            );
+            assert_eq!(
+                add_duration(start, "-1d").unwrap(),
+                datetime!(2024-02-27 23:00 UTC)
+            );
+            assert_eq!(
+                sub_duration(start, "1.5s").unwrap(),
+                datetime!(2024-02-28 22:59:58.5 UTC)
+            );
+            assert_eq!(
+                sub_duration(start, "-1h").unwrap(),
+                datetime!(2024-02-29 00:00 UTC)
+            );
+        }
+
+        #[test]
+        fn should_keep_offset() {
+            // should keep the offset of the input
+            let start = datetime!(2024-01-01 00:00 -05:00);
+            let end = add_duration(start, "1 week").unwrap();
+            assert_eq!(end, datetime!(2024-01-08 00:00 -05:00));
+            assert_eq!(end.offset(), start.offset());
+        }
+
+        #[test]
+        fn should_report_errors() {
+            // should report invalid input and overflow
+            let start = datetime!(2024-01-01 00:00 UTC);
+            assert!(add_duration(start, "invalid").is_err());
+            assert!(add_duration(start, "100000 years").is_err());
+            assert!(sub_duration(start, "100000 years").is_err());
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use millis::{ToMillis, ms};
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============================================================================
+    // Test ms(integer)
+    // ============================================================================
+
+    mod test_ms_integers {
+        use super::*;
+
+        #[test]
+        fn should_infer_untyped_literals() {
+            // should accept untyped integer and float literals
+            assert_eq!(ms(60000).unwrap(), "1m");
+            assert_eq!(ms(-1500.0).unwrap(), "-2s");
+        }
+
+        #[test]
+        fn should_format_literals_above_i32_with_a_suffix() {
+            // should format values beyond i32 once the literal is typed
+            assert_eq!(ms(31557600000_i64).unwrap(), "1y");
+            assert_eq!(ms(30 * 24 * 3600 * 1000_i64).unwrap(), "4w");
+            assert_eq!(ms(31557600000_u64).unwrap(), "1y");
+        }
+
+        #[test]
+        fn should_support_every_integer() {
+            // should format every primitive integer
+            assert_eq!(ms(100i8).unwrap(), "100ms");
+            assert_eq!(ms(1000i16).unwrap(), "1s");
+            assert_eq!(ms(60_000i32).unwrap(), "1m");
+            assert_eq!(ms(60_000i64).unwrap(), "1m");
+            assert_eq!(ms(-60_000i128).unwrap(), "-1m");
+            assert_eq!(ms(60_000isize).unwrap(), "1m");
+            assert_eq!(ms(200u8).unwrap(), "200ms");
+            assert_eq!(ms(1000u16).unwrap(), "1s");
+            assert_eq!(ms(60_000u32).unwrap(), "1m");
+            assert_eq!(ms(60_000u64).unwrap(), "1m");
+            assert_eq!(ms(60_000u128).unwrap(), "1m");
+            assert_eq!(ms(60_000usize).unwrap(), "1m");
+        }
+
+        #[test]
+        fn should_reject_values_outside_i64() {
+            // should return an error for values that do not fit in i64
+            assert!(ms(u64::MAX).is_err());
+            assert!(ms(i128::MIN).is_err());
+            assert!(ms(u128::MAX).is_err());
+            assert_eq!(ms(i64::MAX as u64).unwrap(), "292271023y");
+        }
+    }
+
+    // ============================================================================
+    // Test ms(float)
+    // ============================================================================
+
+    mod test_ms_floats {
+        use super::*;
+
+        #[test]
+        fn should_round_to_milliseconds() {
+            // should round floats to the nearest millisecond
+            assert_eq!(ms(1.5e3f64).unwrap(), "2s");
+            assert_eq!(ms(999.6f32).unwrap(), "1s");
+            assert_eq!(ms(0.4f64).unwrap(), "0ms");
+            assert_eq!(ms(-3.6e6f64).unwrap(), "-1h");
+        }
+
+        #[test]
+        fn should_reject_non_finite_and_huge_values() {
+            // should return an error for NaN, infinities and huge values
+            assert!(ms(f64::NAN).is_err());
+            assert!(ms(f64::INFINITY).is_err());
+            assert!(ms(f32::NEG_INFINITY).is_err());
+            assert!(ms(1e19f64).is_err());
+            assert!(ms(9223372036854775807.0f64).is_err());
+        }
+    }
+
+    // ============================================================================
+    // Test ms(string-like)
+    // ============================================================================
+
+    mod test_ms_strings {
+        use super::*;
+
+        #[test]
+        fn should_support_string_like_types() {
+            // should parse every string-like type
+            let owned = String::from("2h");
+            assert_eq!(ms(&owned).unwrap(), 7200000);
+            assert_eq!(ms(owned).unwrap(), 7200000);
+            assert_eq!(ms(Box::<str>::from("1s")).unwrap(), 1000);
+            assert_eq!(ms(Cow::Borrowed("1m")).unwrap(), 60000);
+            assert_eq!(ms(Cow::<str>::Owned("1d".to_string())).unwrap(), 86400000);
+            assert_eq!(ms(Rc::<str>::from("1w")).unwrap(), 604800000);
+            assert_eq!(ms(Arc::<str>::from("-1s")).unwrap(), -1000);
+            assert!(ms(Arc::<str>::from("invalid")).is_err());
+        }
+    }
+
+    // ============================================================================
+    // Test ToMillis for custom types
+    // ============================================================================
+
+    mod test_to_millis_custom {
+        use super::*;
+
+        struct Timeout(&'static str);
+
+        impl ToMillis for Timeout {
+            type Output = Result<i64, String>;
+
+            fn to_millis(self) -> Result<i64, String> {
+                ms(self.0)
+            }
+        }
+
+        #[test]
+        fn should_allow_custom_impls() {
+            // should allow implementing ToMillis for local types
+            assert_eq!(ms(Timeout("5s")).unwrap(), 5000);
+        }
+    }
+}
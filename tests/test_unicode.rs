@@ -0,0 +1,178 @@
+use millis::{find_durations, parse};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============================================================================
+    // Test parse(unicode digits)
+    // ============================================================================
+
+    mod test_parse_unicode_digits {
+        use super::*;
+
+        #[test]
+        fn should_support_full_width_digits() {
+            // should support full-width digits
+            assert_eq!(parse("５s").unwrap(), 5000);
+            assert_eq!(parse("１２ hours").unwrap(), 43200000);
+            assert_eq!(parse("１.５h").unwrap(), 5400000);
+        }
+
+        #[test]
+        fn should_support_other_decimal_digits() {
+            // should support Arabic-Indic and Devanagari digits
+            assert_eq!(parse("٥ minutes").unwrap(), 300000);
+            assert_eq!(parse("१० s").unwrap(), 10000);
+        }
+
+        #[test]
+        fn should_support_every_digit_block() {
+            // should read every digit the parser accepts, including recent blocks
+            assert_eq!(parse("\u{11F55}s").unwrap(), 5000);
+            assert_eq!(parse("\u{1E4F1}\u{1E4F0}s").unwrap(), 10000);
+            assert_eq!(parse("\u{1D7DB}\u{1D7E2}s").unwrap(), 30000);
+            assert_eq!(parse("\u{1D7FF}ms").unwrap(), 9);
+        }
+
+        #[test]
+        fn should_support_unicode_minus_sign() {
+            // should support the minus sign U+2212
+            assert_eq!(parse("−1h").unwrap(), -3600000);
+        }
+    }
+
+    // ============================================================================
+    // Test parse(vulgar fractions)
+    // ============================================================================
+
+    mod test_parse_vulgar_fractions {
+        use super::*;
+
+        #[test]
+        fn should_support_bare_fractions() {
+            // should support a fraction on its own
+            assert_eq!(parse("½h").unwrap(), 1800000);
+            assert_eq!(parse("¾ minute").unwrap(), 45000);
+            assert_eq!(parse("⅓s").unwrap(), 333);
+        }
+
+        #[test]
+        fn should_support_mixed_numbers() {
+            // should support a whole number followed by a fraction
+            assert_eq!(parse("1¼ hours").unwrap(), 4500000);
+            assert_eq!(parse("2½d").unwrap(), 216000000);
+            assert_eq!(parse("-1½m").unwrap(), -90000);
+        }
+
+        #[test]
+        fn should_reject_fractions_after_decimals() {
+            // should reject a fraction after a decimal number
+            assert!(parse("1.5½h").is_err());
+            assert!(parse("½½h").is_err());
+        }
+    }
+
+    // ============================================================================
+    // Test parse(micro sign)
+    // ============================================================================
+
+    mod test_parse_microseconds {
+        use super::*;
+
+        #[test]
+        fn should_support_micro_sign_and_greek_mu() {
+            // should support the micro sign and Greek mu
+            assert_eq!(parse("10 µs").unwrap(), 0);
+            assert_eq!(parse("2500µs").unwrap(), 3);
+            assert_eq!(parse("2500 μs").unwrap(), 3);
+        }
+
+        #[test]
+        fn should_support_ascii_microseconds() {
+            // should support ASCII microsecond names
+            assert_eq!(parse("4000us").unwrap(), 4);
+            assert_eq!(parse("4000 usecs").unwrap(), 4);
+            assert_eq!(parse("4000 microseconds").unwrap(), 4);
+        }
+    }
+
+    // ============================================================================
+    // Test parse(non-breaking spaces)
+    // ============================================================================
+
+    mod test_parse_spaces {
+        use super::*;
+
+        #[test]
+        fn should_support_non_breaking_spaces() {
+            // should support non-breaking and narrow spaces
+            assert_eq!(parse("10\u{a0}seconds").unwrap(), 10000);
+            assert_eq!(parse("10\u{202f}s").unwrap(), 10000);
+            assert_eq!(parse("10\u{3000}秒").unwrap(), 10000);
+        }
+    }
+
+    // ============================================================================
+    // Test parse(CJK units)
+    // ============================================================================
+
+    mod test_parse_cjk_units {
+        use super::*;
+
+        #[test]
+        fn should_support_cjk_unit_names() {
+            // should support Chinese and Japanese unit names
+            assert_eq!(parse("５分").unwrap(), 300000);
+            assert_eq!(parse("5分钟").unwrap(), 300000);
+            assert_eq!(parse("2小时").unwrap(), 7200000);
+            assert_eq!(parse("3時間").unwrap(), 10800000);
+            assert_eq!(parse("1天").unwrap(), 86400000);
+            assert_eq!(parse("500毫秒").unwrap(), 500);
+        }
+
+        #[test]
+        fn should_not_read_date_markers_as_units() {
+            // should reject the year, month, day and o'clock markers of dates
+            assert!(parse("2024年").is_err());
+            assert!(parse("3月").is_err());
+            assert!(parse("5日").is_err());
+            assert!(parse("3時").is_err());
+            assert_eq!(parse("3ヶ月").unwrap(), 7889400000);
+        }
+    }
+
+    // ============================================================================
+    // Test find_durations(unicode text)
+    // ============================================================================
+
+    mod test_find_durations_unicode {
+        use super::*;
+
+        #[test]
+        fn should_report_spans_in_original_text() {
+            // should report spans into the original, unnormalized text
+            let text = "waited ½h, then １０ µs";
+            let found: Vec<_> = find_durations(text).collect();
+            assert_eq!(found.len(), 2);
+            assert_eq!(&text[found[0].range()], "½h");
+            assert_eq!(found[0].millis, 1800000);
+            assert_eq!(&text[found[1].range()], "１０ µs");
+        }
+
+        #[test]
+        fn should_find_durations_in_cjk_text() {
+            // should find durations in text without spaces
+            let found: Vec<_> = find_durations("等待５分钟后重启").collect();
+            assert_eq!(found.len(), 1);
+            assert_eq!(found[0].text, "５分钟");
+            assert_eq!(found[0].millis, 300000);
+        }
+
+        #[test]
+        fn should_skip_cjk_dates() {
+            // should not find durations in dates
+            assert_eq!(find_durations("2024年3月5日に再起動").count(), 0);
+        }
+    }
+}